@@ -0,0 +1,44 @@
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::bench;
+use aoc::days;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Time parsing and solving separately, using the timings each solution reports
+fn bench_days(c: &mut Criterion) {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    for (index, solution) in days::SOLUTIONS.iter().enumerate() {
+        let day = index as u8 + 1;
+        let input = aoc_core::input::load(Some(&workspace.join(bench::input_path(day)))).expect("Unable to read input");
+
+        // Days added with `aoc new` start with an empty input and an unsolved stub
+        if input.trim().is_empty() {
+            continue;
+        }
+
+        for part in 1..=2 {
+            if solution.solve(part, &input).is_none() {
+                continue;
+            }
+
+            let mut group = c.benchmark_group(format!("day_{:02}/part{}", day, part));
+            group.sample_size(10);
+            group.bench_function("parse", |b| {
+                b.iter_custom(|iters| total_time(iters, || solution.solve(part, &input).unwrap().unwrap().parse_time))
+            });
+            group.bench_function("solve", |b| {
+                b.iter_custom(|iters| total_time(iters, || solution.solve(part, &input).unwrap().unwrap().solve_time))
+            });
+            group.finish();
+        }
+    }
+}
+
+fn total_time(iters: u64, mut time: impl FnMut() -> Duration) -> Duration {
+    (0..iters).map(|_| time()).sum()
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use aoc_core::answers::Answers;
+
+// Generate an integration test for each recorded answer in answers.toml
+fn main() {
+    let answers_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+    println!("cargo:rerun-if-changed={}", answers_path.display());
+
+    let answers = Answers::load(&answers_path).unwrap_or_else(|err| panic!("{}", err));
+
+    let mut tests = String::new();
+    for (key, part) in answers.parts() {
+        let day: u8 = key.strip_prefix("day_").and_then(|day| day.parse().ok()).expect("Expected tables named like [day_01]");
+        tests += &format!("#[test]\nfn day_{:02}_part{}() {{\n    verify({}, {});\n}}\n\n", day, part, day, part);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("answers.rs"), tests).expect("Unable to write generated tests");
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+// Median timings for a single part of a single day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Timing {
+    pub fn parse_time(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns)
+    }
+
+    pub fn solve_time(&self) -> Duration {
+        Duration::from_nanos(self.solve_ns)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time() + self.solve_time()
+    }
+}
+
+// Saved timings to compare later runs against
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub timings: Vec<Timing>,
+}
+
+impl Baseline {
+    // A missing baseline is empty, so the first run has nothing to compare against
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Baseline::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|err| format!("Unable to read baseline {}: {}", path.display(), err))?;
+        toml::from_str(&contents).map_err(|err| format!("Unable to parse baseline {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|err| format!("Unable to serialise baseline: {}", err))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| format!("Unable to create {}: {}", parent.display(), err))?;
+        }
+        fs::write(path, contents).map_err(|err| format!("Unable to write baseline {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Timing> {
+        self.timings.iter().find(|timing| timing.day == day && timing.part == part)
+    }
+}
+
+// Real puzzle input for a day, relative to the workspace root
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day_{:02}/src/bin/input.txt", day))
+}
+
+// Solve a part several times, taking the median parse and solve times
+// Returns None if the part has not been solved yet
+pub fn measure(solution: &dyn Solution, day: u8, part: u8, input: &str, runs: usize) -> Option<Result<Timing, ParseError>> {
+    let mut parse_times = Vec::new();
+    let mut solve_times = Vec::new();
+
+    for _ in 0..runs.max(1) {
+        match solution.solve(part, input)? {
+            Ok(output) => {
+                parse_times.push(output.parse_time);
+                solve_times.push(output.solve_time);
+            }
+            Err(err) => return Some(Err(err)),
+        }
+    }
+
+    Some(Ok(Timing {
+        day,
+        part,
+        parse_ns: median(&mut parse_times).as_nanos() as u64,
+        solve_ns: median(&mut solve_times).as_nanos() as u64,
+    }))
+}
+
+fn median(durations: &mut [Duration]) -> Duration {
+    durations.sort();
+    durations.get(durations.len() / 2).copied().unwrap_or_default()
+}
+
+// Percentage change in total time against the baseline, positive when slower
+pub fn change(timing: &Timing, baseline: &Timing) -> f64 {
+    let before = baseline.total_time().as_nanos() as f64;
+    let after = timing.total_time().as_nanos() as f64;
+    if before == 0.0 { return 0.0; };
+
+    (after - before) / before * 100.0
+}
+
+pub fn is_regression(timing: &Timing, baseline: &Timing, threshold: f64) -> bool {
+    change(timing, baseline) > threshold
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(part: u8, parse_ns: u64, solve_ns: u64) -> Timing {
+        Timing { day: 1, part, parse_ns, solve_ns }
+    }
+
+    #[test]
+    fn it_round_trips_baselines() {
+        let baseline = Baseline { timings: vec![timing(1, 100, 2_000), timing(2, 300, 4_000)] };
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.get(1, 2), Some(&timing(2, 300, 4_000)));
+        assert_eq!(Baseline::load(&path), Ok(Baseline::default()));
+    }
+
+    #[test]
+    fn it_detects_regressions() {
+        let baseline = timing(1, 100, 900);
+        assert_eq!(change(&timing(1, 100, 1_400), &baseline), 50.0);
+        assert!(is_regression(&timing(1, 100, 1_400), &baseline, 10.0));
+        assert!(!is_regression(&timing(1, 100, 1_000), &baseline, 10.0));
+        assert!(!is_regression(&timing(1, 50, 500), &baseline, 10.0));
+    }
+
+    #[test]
+    fn it_formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_core::Solution;
+
+// Ordered by day, so day N is found at index N - 1
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    let index = usize::from(day).checked_sub(1)?;
+    SOLUTIONS.get(index).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_gets_solutions_by_day() {
+        assert!(get(0).is_none());
+        assert!(get(1).is_some());
+        assert!(get(SOLUTIONS.len() as u8).is_some());
+        assert!(get(SOLUTIONS.len() as u8 + 1).is_none());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bench;
+pub mod days;
+pub mod scaffold;
+pub mod store;
+pub mod verify;
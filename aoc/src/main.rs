@@ -0,0 +1,252 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc::bench::{self, Baseline};
+use aoc::days;
+use aoc::scaffold;
+use aoc::store::{self, InputStore};
+use aoc::verify::{self, Outcome};
+use aoc_core::answers::Answers;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single part of a single day
+    Run {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Path to the puzzle input, read from AOC_INPUT, stdin or the input cache if not provided
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Download a day's puzzle input into the input cache
+    Fetch {
+        #[arg(long)]
+        day: u8,
+        /// Download the input again, even if it has already been cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Time parsing and solving for each day against its real input
+    Bench {
+        /// Only benchmark a single day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Number of times to solve each part, the median time is reported
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Saved timings to compare against
+        #[arg(long, default_value = "target/aoc-bench-baseline.toml")]
+        baseline: PathBuf,
+        /// Save these timings as the new baseline
+        #[arg(long)]
+        save_baseline: bool,
+        /// Percentage slowdown against the baseline which counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check each day's answer for its real input against the recorded answers
+    Verify {
+        /// Only verify a single day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Recorded answers for each day and part
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Generate the crate for a new day, and register it in the workspace and runner
+    New {
+        #[arg(long)]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Fetch { day, force } => fetch(day, force),
+        Command::Bench { day, runs, baseline, save_baseline, threshold } => {
+            run_benchmarks(day, runs, &baseline, save_baseline, threshold)
+        }
+        Command::Verify { day, answers } => verify_answers(day, &answers),
+        Command::New { day } => new_day(day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(day: u8, part: u8, input: Option<PathBuf>) -> Result<(), String> {
+    let solution = days::get(day).ok_or(format!("Day {} has not been solved yet", day))?;
+    let input = load_input(day, input)?;
+
+    match solution.solve(part, &input) {
+        Some(Ok(output)) => {
+            println!("The answer for day {} part {} is: {}", day, part, output);
+            Ok(())
+        }
+        Some(Err(err)) => Err(format!("Invalid input for day {} part {}\n{}", day, part, err.report(&input))),
+        None => Err(format!("Day {} part {} has not been solved yet", day, part)),
+    }
+}
+
+// Input given on the command line, through AOC_INPUT or on stdin, otherwise from the input cache
+fn load_input(day: u8, input: Option<PathBuf>) -> Result<String, String> {
+    if input.is_none() && env::var_os(aoc_core::input::INPUT_VAR).is_none() && io::stdin().is_terminal() {
+        return InputStore::from_env().get(store::YEAR, day);
+    }
+
+    aoc_core::input::load(input.as_deref()).map_err(|err| format!("Unable to read input: {}", err))
+}
+
+fn fetch(day: u8, force: bool) -> Result<(), String> {
+    let store = InputStore::from_env();
+    match force {
+        true => store.refresh(store::YEAR, day)?,
+        false => store.get(store::YEAR, day)?,
+    };
+
+    println!("Input for day {} is cached at {}", day, store.path(store::YEAR, day).display());
+    Ok(())
+}
+
+fn run_benchmarks(day: Option<u8>, runs: usize, baseline_path: &Path, save_baseline: bool, threshold: f64) -> Result<(), String> {
+    let days: Vec<u8> = match day {
+        Some(day) => {
+            days::get(day).ok_or(format!("Day {} has not been solved yet", day))?;
+            vec![day]
+        }
+        None => (1..=days::SOLUTIONS.len() as u8).collect(),
+    };
+
+    let baseline = Baseline::load(baseline_path)?;
+    let mut timings = Vec::new();
+    let mut regressions = Vec::new();
+
+    println!("{:>3} {:>4} {:>10} {:>10} {:>10} {:>10}", "Day", "Part", "Parse", "Solve", "Total", "Change");
+    for day in days {
+        let solution = days::get(day).unwrap();
+        let input_path = bench::input_path(day);
+        let input = aoc_core::input::load(Some(&input_path)).map_err(|err| format!("Unable to read {}: {}", input_path.display(), err))?;
+
+        // Days added with `aoc new` start with an empty input and an unsolved stub
+        if input.trim().is_empty() {
+            println!("{:>3} {:>4} {:>10}", day, "-", "no input");
+            continue;
+        }
+
+        for part in 1..=2 {
+            let timing = match bench::measure(solution, day, part, &input, runs) {
+                Some(Ok(timing)) => timing,
+                Some(Err(err)) => return Err(format!("Invalid input for day {} part {}\n{}", day, part, err.report(&input))),
+                None => continue,
+            };
+
+            let change = match baseline.get(day, part) {
+                Some(previous) if bench::is_regression(&timing, previous, threshold) => {
+                    regressions.push((day, part));
+                    format!("{:+.1}% !", bench::change(&timing, previous))
+                }
+                Some(previous) => format!("{:+.1}%", bench::change(&timing, previous)),
+                None => String::from("-"),
+            };
+
+            println!(
+                "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10}",
+                day,
+                part,
+                bench::format_duration(timing.parse_time()),
+                bench::format_duration(timing.solve_time()),
+                bench::format_duration(timing.total_time()),
+                change
+            );
+            timings.push(timing);
+        }
+    }
+
+    if save_baseline {
+        Baseline { timings }.save(baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+
+    match regressions.as_slice() {
+        [] => Ok(()),
+        regressions => {
+            let parts: Vec<String> = regressions.iter().map(|(day, part)| format!("day {} part {}", day, part)).collect();
+            Err(format!("Slower than the baseline by more than {}%: {}", threshold, parts.join(", ")))
+        }
+    }
+}
+
+fn verify_answers(day: Option<u8>, answers_path: &Path) -> Result<(), String> {
+    let answers = Answers::load(answers_path)?;
+    let parts: Vec<(u8, u8)> = verify::recorded_parts(&answers)?
+        .into_iter()
+        .filter(|(answer_day, _)| day.is_none_or(|day| day == *answer_day))
+        .collect();
+    if parts.is_empty() {
+        return Err(format!("No answers recorded in {}", answers_path.display()));
+    }
+
+    let mut failures = Vec::new();
+    for (day, part) in parts {
+        let solution = days::get(day).ok_or(format!("Day {} has not been solved yet", day))?;
+        let input_path = bench::input_path(day);
+        let input = aoc_core::input::load(Some(&input_path)).map_err(|err| format!("Unable to read {}: {}", input_path.display(), err))?;
+        let expected = answers.get(&verify::key(day), part).unwrap();
+
+        match verify::check(solution, part, &input, expected) {
+            Outcome::Correct => println!("ok   day {} part {}: {}", day, part, expected),
+            Outcome::Incorrect { expected, actual } => {
+                println!("FAIL day {} part {}: expected {}, found {}", day, part, expected, actual);
+                failures.push((day, part));
+            }
+            Outcome::Invalid(err) => {
+                println!("FAIL day {} part {}: invalid input\n{}", day, part, err.report(&input));
+                failures.push((day, part));
+            }
+            Outcome::Unsolved => {
+                println!("FAIL day {} part {}: has not been solved yet", day, part);
+                failures.push((day, part));
+            }
+        }
+    }
+
+    match failures.as_slice() {
+        [] => Ok(()),
+        failures => {
+            let parts: Vec<String> = failures.iter().map(|(day, part)| format!("day {} part {}", day, part)).collect();
+            Err(format!("Incorrect answers for: {}", parts.join(", ")))
+        }
+    }
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let changed = scaffold::new_day(Path::new("."), day, days::SOLUTIONS.len())?;
+    for path in changed {
+        println!("Wrote {}", path.display());
+    }
+
+    let name = scaffold::crate_name(day);
+    println!("\nPaste the puzzle input into {}/src/bin/input.txt and the example into {}/examples/01.txt", name, name);
+    println!("Then set the example's answer in {}/examples/answers.toml and run: cargo test -p {}", name, name);
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Files for a new day's crate, relative to the crate's directory
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml.tmpl")),
+    ("build.rs", include_str!("../templates/day/build.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tmpl")),
+    ("src/part1.rs", include_str!("../templates/day/part1.rs.tmpl")),
+    ("src/bin/part1.rs", include_str!("../templates/day/bin/part1.rs.tmpl")),
+    ("src/bin/input.txt", ""),
+    ("examples/01.txt", ""),
+    ("examples/answers.toml", include_str!("../templates/day/answers.toml.tmpl")),
+];
+
+pub fn crate_name(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{crate}}", &crate_name(day))
+        .replace("{{struct}}", &format!("Day{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+// Insert line after the last line starting with prefix, so that days stay in order
+// Returns None if there is no line with the prefix to insert after
+pub fn insert_after_last(contents: &str, prefix: &str, line: &str) -> Option<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let index = lines.iter().rposition(|existing| existing.trim_start().starts_with(prefix))?;
+
+    let mut lines = lines;
+    lines.insert(index + 1, line);
+    Some(lines.join("\n") + "\n")
+}
+
+// Generate a crate for day, then register it in the workspace and the runner
+// Returns the paths of each file created or changed
+pub fn new_day(root: &Path, day: u8, solved_days: usize) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not part of Advent of Code", day));
+    }
+    // Solutions are looked up by their index, so days can't be skipped
+    if usize::from(day) != solved_days + 1 {
+        return Err(format!("The next day to add is day {}", solved_days + 1));
+    }
+
+    let name = crate_name(day);
+    let day_dir = root.join(&name);
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let registrations = [
+        (root.join("Cargo.toml"), "\"day_", format!("    \"{}\",", name)),
+        (root.join("aoc/Cargo.toml"), "day_", format!("{} = {{ path = \"../{}\" }}", name, name)),
+        (root.join("aoc/src/days.rs"), "&day_", format!("    &{}::Day{:02},", name, day)),
+    ];
+
+    // Check every file can be registered before changing anything
+    let mut updates = Vec::new();
+    for (path, prefix, line) in registrations {
+        let contents = fs::read_to_string(&path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+        let updated = insert_after_last(&contents, prefix, &line)
+            .ok_or(format!("Unable to find where to register {} in {}", name, path.display()))?;
+        updates.push((path, updated));
+    }
+
+    let mut changed = Vec::new();
+    for (file, template) in TEMPLATES {
+        let path = day_dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).map_err(|err| format!("Unable to create {}: {}", path.display(), err))?;
+        fs::write(&path, render(template, day)).map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+        changed.push(path);
+    }
+
+    for (path, updated) in updates {
+        fs::write(&path, updated).map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_renders_templates() {
+        let rendered = render("use {{crate}}; struct {{struct}}; // Day {{day}}", 8);
+        assert_eq!(rendered, "use day_08; struct Day08; // Day 8");
+    }
+
+    #[test]
+    fn it_inserts_after_the_last_day() {
+        let members = "members = [\n    \"aoc\",\n    \"day_01\",\n    \"day_02\",\n]\n";
+        assert_eq!(
+            insert_after_last(members, "\"day_", "    \"day_03\","),
+            Some(String::from("members = [\n    \"aoc\",\n    \"day_01\",\n    \"day_02\",\n    \"day_03\",\n]\n"))
+        );
+        assert_eq!(insert_after_last(members, "&day_", "    &day_03::Day03,"), None);
+    }
+
+    #[test]
+    fn it_scaffolds_a_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\n    \"day_01\",\n]\n").unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\nday_01 = { path = \"../day_01\" }\n").unwrap();
+        fs::write(root.join("aoc/src/days.rs"), "&[\n    &day_01::Day01,\n];\n").unwrap();
+
+        assert_eq!(new_day(&root, 3, 1), Err(String::from("The next day to add is day 2")));
+        let changed = new_day(&root, 2, 1).unwrap();
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        let lib = fs::read_to_string(root.join("day_02/src/lib.rs")).unwrap();
+        let second_attempt = new_day(&root, 2, 1);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(changed.len(), TEMPLATES.len() + 3);
+        assert_eq!(days, "&[\n    &day_01::Day01,\n    &day_02::Day02,\n];\n");
+        assert!(lib.contains("impl Solution for Day02"));
+        assert!(second_attempt.unwrap_err().ends_with("day_02 already exists"));
+    }
+}
@@ -0,0 +1,198 @@
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const YEAR: u16 = 2023;
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_CACHE_DIR: &str = "inputs";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+// Source of puzzle inputs which aren't cached yet
+pub trait Fetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+// Downloads inputs from the Advent of Code website, using the session cookie of a logged in user
+pub struct HttpFetch {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl HttpFetch {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        HttpFetch { base_url: base_url.into(), session }
+    }
+
+    pub fn from_env() -> Self {
+        HttpFetch::new(DEFAULT_URL, env::var(SESSION_VAR).ok())
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let session = self.session.as_ref().ok_or(format!("set {} to download puzzle inputs", SESSION_VAR))?;
+        let url = format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day);
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "github.com/DafyddLlyr/advent_of_code_2023_rust")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => format!("{} responded with status {}", url, status),
+                err => format!("unable to reach {}: {}", url, err),
+            })?;
+
+        let mut input = String::new();
+        response.into_reader().read_to_string(&mut input).map_err(|err| format!("unable to read {}: {}", url, err))?;
+        Ok(input)
+    }
+}
+
+// Puzzle inputs cached on disk by year and day, fetched the first time they're needed
+pub struct InputStore {
+    cache_dir: PathBuf,
+    fetcher: Box<dyn Fetch>,
+}
+
+impl InputStore {
+    pub fn new(cache_dir: impl Into<PathBuf>, fetcher: Box<dyn Fetch>) -> Self {
+        InputStore { cache_dir: cache_dir.into(), fetcher }
+    }
+
+    pub fn from_env() -> Self {
+        let cache_dir = env::var_os(CACHE_VAR).map_or(PathBuf::from(DEFAULT_CACHE_DIR), PathBuf::from);
+        InputStore::new(cache_dir, Box::new(HttpFetch::from_env()))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day_{:02}.txt", day))
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.path(year, day).exists()
+    }
+
+    // Cached input if there is one, otherwise fetch and cache it
+    pub fn get(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.path(year, day);
+        if path.exists() {
+            let input = fs::read_to_string(&path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+            return Ok(aoc_core::input::normalise(&input));
+        }
+
+        self.refresh(year, day)
+    }
+
+    // Fetch input, replacing any cached copy
+    pub fn refresh(&self, year: u16, day: u8) -> Result<String, String> {
+        let input = self.fetcher.fetch(year, day).map_err(|err| format!("Unable to fetch input for {} day {}: {}", year, day, err))?;
+
+        let path = self.path(year, day);
+        write(&path, &input)?;
+        Ok(aoc_core::input::normalise(&input))
+    }
+}
+
+fn write(path: &Path, input: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("Unable to create {}: {}", parent.display(), err))?;
+    }
+    fs::write(path, input).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::rc::Rc;
+    use std::thread;
+
+    struct CountingFetch {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Fetch for CountingFetch {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("input for {} day {}\r\n", year, day))
+        }
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-store-{}-{}", name, std::process::id()))
+    }
+
+    // Serve a single request, returning the request line and headers it received
+    fn stub_server(status: &str, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn it_caches_fetched_input() {
+        let calls = Rc::new(Cell::new(0));
+        let dir = cache_dir("cache");
+        let store = InputStore::new(&dir, Box::new(CountingFetch { calls: calls.clone() }));
+
+        let first = store.get(2023, 7);
+        let cached = store.is_cached(2023, 7);
+        let second = store.get(2023, 7);
+        let path = store.path(2023, 7);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, Ok(String::from("input for 2023 day 7")));
+        assert_eq!(second, first);
+        assert!(cached);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(path, dir.join("2023").join("day_07.txt"));
+    }
+
+    #[test]
+    fn it_fetches_input_over_http() {
+        let (url, server) = stub_server("200 OK", "1abc2\npqr3stu8vwx\n");
+        let dir = cache_dir("http");
+        let store = InputStore::new(&dir, Box::new(HttpFetch::new(url, Some(String::from("abc123")))));
+
+        let input = store.get(2023, 1);
+        let request = server.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(input, Ok(String::from("1abc2\npqr3stu8vwx")));
+        assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|header| header.eq_ignore_ascii_case("cookie: session=abc123")));
+    }
+
+    #[test]
+    fn it_reports_fetch_errors() {
+        let (url, server) = stub_server("404 Not Found", "");
+        let store = InputStore::new(cache_dir("error"), Box::new(HttpFetch::new(url.clone(), Some(String::from("abc123")))));
+
+        let input = store.get(2023, 26);
+        server.join().unwrap();
+
+        assert_eq!(
+            input,
+            Err(format!("Unable to fetch input for 2023 day 26: {}/2023/day/26/input responded with status 404", url))
+        );
+        assert!(!store.is_cached(2023, 26));
+        assert!(HttpFetch::new(url, None).fetch(2023, 1).unwrap_err().contains(SESSION_VAR));
+    }
+}
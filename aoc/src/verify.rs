@@ -0,0 +1,74 @@
+use aoc_core::answers::Answers;
+use aoc_core::{ParseError, Solution};
+
+// Recorded answers have a table for each day, e.g. [day_01] part1 = "55607"
+pub fn key(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+// Every day and part with a recorded answer, in order
+pub fn recorded_parts(answers: &Answers) -> Result<Vec<(u8, u8)>, String> {
+    answers
+        .parts()
+        .into_iter()
+        .map(|(key, part)| match key.strip_prefix("day_").and_then(|day| day.parse().ok()) {
+            Some(day) => Ok((day, part)),
+            None => Err(format!("expected a table named like [day_01], found [{}]", key)),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect { expected: String, actual: String },
+    Invalid(ParseError),
+    Unsolved,
+}
+
+// Solve a part and compare it against its recorded answer
+pub fn check(solution: &dyn Solution, part: u8, input: &str, expected: &str) -> Outcome {
+    match solution.solve(part, input) {
+        Some(Ok(output)) if output.answer == expected => Outcome::Correct,
+        Some(Ok(output)) => Outcome::Incorrect { expected: expected.to_string(), actual: output.answer },
+        Some(Err(err)) => Outcome::Invalid(err),
+        None => Outcome::Unsolved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Answer;
+
+    // Every day has both parts solved, so stand in for a day without a part 2
+    struct PartOneOnly;
+
+    impl Solution for PartOneOnly {
+        fn part1(&self, input: &str) -> Answer {
+            crate::days::get(1).unwrap().part1(input)
+        }
+    }
+
+    #[test]
+    fn it_finds_recorded_parts() {
+        let answers = Answers::parse("[day_07]\npart1 = \"6440\"\n\n[day_01]\npart1 = \"142\"\npart2 = \"281\"\n").unwrap();
+        assert_eq!(recorded_parts(&answers), Ok(vec![(1, 1), (1, 2), (7, 1)]));
+        assert_eq!(answers.get(&key(7), 1), Some("6440"));
+
+        let answers = Answers::parse("[first]\npart1 = \"1\"").unwrap();
+        assert_eq!(recorded_parts(&answers), Err(String::from("expected a table named like [day_01], found [first]")));
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        let solution = crate::days::get(1).unwrap();
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!(check(solution, 1, input, "142"), Outcome::Correct);
+        assert_eq!(
+            check(solution, 1, input, "143"),
+            Outcome::Incorrect { expected: String::from("143"), actual: String::from("142") }
+        );
+        assert_eq!(check(&PartOneOnly, 2, input, "1"), Outcome::Unsolved);
+    }
+}
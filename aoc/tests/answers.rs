@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use aoc::days;
+use aoc::verify::{self, Outcome};
+use aoc_core::answers::Answers;
+
+// Solve a part against its real input, failing if it doesn't match the recorded answer
+fn verify(day: u8, part: u8) {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let answers = Answers::load(&workspace.join("answers.toml")).unwrap();
+    let input = aoc_core::input::load(Some(&workspace.join(aoc::bench::input_path(day)))).unwrap();
+    let solution = days::get(day).unwrap();
+
+    let outcome = verify::check(solution, part, &input, answers.get(&verify::key(day), part).unwrap());
+    assert_eq!(outcome, Outcome::Correct, "day {} part {}", day, part);
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+// Expected answers, with a table of parts for each day or example, e.g. [day_01] part1 = "55607"
+// Answers are strings as each day uses its own numeric type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    tables: BTreeMap<String, PartAnswers>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| format!("Unable to read answers {}: {}", path.display(), err))?;
+        Answers::parse(&contents).map_err(|err| format!("Unable to parse answers {}: {}", path.display(), err))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|err| err.to_string())
+    }
+
+    pub fn get(&self, name: &str, part: u8) -> Option<&str> {
+        let answers = self.tables.get(name)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    // Every table name and part with an answer, in order
+    pub fn parts(&self) -> Vec<(&str, u8)> {
+        let mut parts = Vec::new();
+        for (name, answers) in &self.tables {
+            if answers.part1.is_some() { parts.push((name.as_str(), 1)); };
+            if answers.part2.is_some() { parts.push((name.as_str(), 2)); };
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers = Answers::parse("[day_01]\npart1 = \"142\"\npart2 = \"281\"\n\n[02]\npart1 = \"6440\"\n").unwrap();
+        assert_eq!(answers.get("day_01", 2), Some("281"));
+        assert_eq!(answers.get("02", 1), Some("6440"));
+        assert_eq!(answers.get("02", 2), None);
+        assert_eq!(answers.get("day_02", 1), None);
+        assert_eq!(answers.parts(), vec![("02", 1), ("day_01", 1), ("day_01", 2)]);
+    }
+
+    #[test]
+    fn it_rejects_unknown_parts() {
+        assert!(Answers::parse("[day_01]\npart3 = \"1\"").is_err());
+    }
+}
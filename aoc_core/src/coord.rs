@@ -0,0 +1,53 @@
+// Grid position as (x, y), with (0, 0) in the top left corner
+pub type Coord = (usize, usize);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    // Take a single step from coord, returns None if this would leave the top or left edge
+    // Bottom and right edges are unknown here, so callers must check these themselves
+    pub fn step(&self, (x, y): Coord) -> Option<Coord> {
+        match self {
+            Direction::Up => Some((x, y.checked_sub(1)?)),
+            Direction::Down => Some((x, y + 1)),
+            Direction::Left => Some((x.checked_sub(1)?, y)),
+            Direction::Right => Some((x + 1, y)),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_steps_in_each_direction() {
+        assert_eq!(Direction::Up.step((1, 1)), Some((1, 0)));
+        assert_eq!(Direction::Down.step((1, 1)), Some((1, 2)));
+        assert_eq!(Direction::Left.step((1, 1)), Some((0, 1)));
+        assert_eq!(Direction::Right.step((1, 1)), Some((2, 1)));
+    }
+
+    #[test]
+    fn it_does_not_step_past_top_left() {
+        assert_eq!(Direction::Up.step((0, 0)), None);
+        assert_eq!(Direction::Left.step((0, 0)), None);
+    }
+}
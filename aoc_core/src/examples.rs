@@ -0,0 +1,71 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use crate::answers::Answers;
+use crate::{input, Solution};
+
+// Generate a test for each answer in the day's examples/answers.toml, where [01] is the answer for examples/01.txt
+// Called from each day's build script, the tests are then included with examples!
+pub fn generate_tests() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let examples_dir = Path::new(&manifest_dir).join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let answers_path = examples_dir.join("answers.toml");
+    let answers = match answers_path.exists() {
+        true => Answers::load(&answers_path).unwrap_or_else(|err| panic!("{}", err)),
+        false => Answers::default(),
+    };
+
+    let mut tests = String::new();
+    for (name, part) in answers.parts() {
+        if !name.chars().all(|char| char.is_ascii_alphanumeric() || char == '_') {
+            panic!("Example names must be letters, numbers or underscores, found [{}]", name);
+        }
+
+        let example_path = examples_dir.join(format!("{}.txt", name));
+        if !example_path.exists() {
+            panic!("{} has an answer for [{}], but there is no {}", answers_path.display(), name, example_path.display());
+        }
+
+        tests += &format!(
+            "#[test]\nfn example_{}_part{}() {{\n    aoc_core::examples::check(SOLUTION, {}, include_str!({:?}), {:?});\n}}\n\n",
+            name,
+            part,
+            part,
+            example_path.display().to_string(),
+            answers.get(name, part).unwrap()
+        );
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests).expect("Unable to write generated example tests");
+}
+
+// Panics, so the test fails, unless solving the example gives the expected answer
+pub fn check(solution: &dyn Solution, part: u8, example: &str, expected: &str) {
+    let example = input::normalise(example);
+    match solution.solve(part, &example) {
+        Some(Ok(output)) => assert_eq!(output.answer, expected, "Incorrect answer for part {}", part),
+        Some(Err(err)) => panic!("Unable to parse example for part {}\n{}", part, err.report(&example)),
+        None => panic!("Part {} has not been solved yet", part),
+    }
+}
+
+// Include the tests generated by generate_tests, solving each example with solution
+#[macro_export]
+macro_rules! examples {
+    ($solution:expr) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            // Not used until the day has example answers
+            #[allow(dead_code)]
+            const SOLUTION: &dyn $crate::Solution = &$solution;
+
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
@@ -0,0 +1,228 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Coord, Direction, ParseError};
+
+// Rectangular grid of tiles, stored row by row and indexed by (x, y)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    // Panics if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        assert!(rows.iter().all(|row| row.len() == width), "Grid rows must all be the same length");
+
+        Grid { tiles: rows.into_iter().flatten().collect(), width, height }
+    }
+
+    // Parse one tile per character, where tile returns None for characters which aren't valid tiles
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut tiles: Vec<T> = Vec::new();
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+
+        for (line_index, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (column, char) in line.char_indices() {
+                let parsed = tile(char).ok_or(ParseError::new(line_index, column, format!("invalid tile '{}'", char)))?;
+                tiles.push(parsed);
+                line_width += 1;
+            }
+
+            let width = *width.get_or_insert(line_width);
+            if line_width != width {
+                return Err(ParseError::new(line_index, line.len(), format!("expected {} tiles, found {}", width, line_width)));
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { tiles, width, height }),
+            _ => Err(ParseError::new(0, 0, "expected a grid of tiles")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        if !self.contains(coord) { return None; };
+        self.tiles.get(self.index_of(coord))
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if !self.contains(coord) { return None; };
+        let index = self.index_of(coord);
+        self.tiles.get_mut(index)
+    }
+
+    // Take a single step from coord, returns None if this would leave the grid on any edge
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        direction.step(coord).filter(|&next| self.contains(next))
+    }
+
+    // Up, down, left and right neighbours within the grid
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(coord, direction))
+    }
+
+    // Neighbours within the grid, including diagonals
+    pub fn neighbours_with_diagonals(&self, (x, y): Coord) -> impl Iterator<Item = Coord> + '_ {
+        (-1..=1)
+            .flat_map(|dy: isize| (-1..=1).map(move |dx: isize| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&next| self.contains(next))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.tiles.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.tiles.iter().skip(x).step_by(self.width).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // All coords, in reading order
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.tiles.iter())
+    }
+
+    // First coord, in reading order, where the tile matches predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter().find(|(_, tile)| predicate(tile)).map(|(coord, _)| coord)
+    }
+
+    fn index_of(&self, (x, y): Coord) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, tile: T) -> Self {
+        Grid { tiles: vec![tile; width * height], width, height }
+    }
+
+    // Swap rows and columns, reflecting along the top left to bottom right diagonal
+    pub fn transpose(&self) -> Self {
+        let tiles = self.columns().flatten().cloned().collect();
+        Grid { tiles, width: self.height, height: self.width }
+    }
+
+    // The first column, read from bottom to top, becomes the first row
+    pub fn rotate_clockwise(&self) -> Self {
+        let tiles = self.columns().flat_map(|column| column.collect::<Vec<_>>().into_iter().rev()).cloned().collect();
+        Grid { tiles, width: self.height, height: self.width }
+    }
+
+    // The last column, read from top to bottom, becomes the first row
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let tiles = (0..self.width).rev().flat_map(|x| self.column(x)).cloned().collect();
+        Grid { tiles, width: self.height, height: self.width }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord).expect("Coord is outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord).expect("Coord is outside of the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn it_parses_tiles() {
+        let grid = Grid::parse("#.\n.#", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+        assert_eq!(grid, Ok(Grid::from_rows(vec![vec![true, false], vec![false, true]])));
+    }
+
+    #[test]
+    fn it_rejects_invalid_grids() {
+        let digits = |char: char| char.to_digit(10);
+        assert_eq!(Grid::parse("12\n3x", digits), Err(ParseError::new(1, 1, "invalid tile 'x'")));
+        assert_eq!(Grid::parse("12\n345", digits), Err(ParseError::new(1, 3, "expected 2 tiles, found 3")));
+        assert_eq!(Grid::parse("", digits), Err(ParseError::new(0, 0, "expected a grid of tiles")));
+    }
+
+    #[test]
+    fn it_gets_tiles_within_bounds() {
+        let grid = example();
+        assert_eq!(grid.get((2, 0)), Some(&'c'));
+        assert_eq!(grid.get((0, 1)), Some(&'d'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid[(1, 1)], 'e');
+    }
+
+    #[test]
+    fn it_finds_neighbours() {
+        let grid = example();
+        assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours((1, 1)).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (2, 1)]);
+        assert_eq!(grid.neighbours_with_diagonals((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbours_with_diagonals((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn it_views_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn it_transposes_and_rotates() {
+        let grid = example();
+        assert_eq!(grid.transpose(), Grid::parse("ad\nbe\ncf", Some).unwrap());
+        assert_eq!(grid.rotate_clockwise(), Grid::parse("da\neb\nfc", Some).unwrap());
+        assert_eq!(grid.rotate_anticlockwise(), Grid::parse("cf\nbe\nad", Some).unwrap());
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+    }
+}
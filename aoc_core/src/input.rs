@@ -0,0 +1,72 @@
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+// Load puzzle input from the first of: the given path, the AOC_INPUT environment variable, or stdin
+pub fn load(path: Option<&Path>) -> io::Result<String> {
+    let path = path.map(Path::to_path_buf).or_else(|| env::var_os(INPUT_VAR).map(PathBuf::from));
+
+    let input = match path {
+        Some(path) => fs::read_to_string(path)?,
+        None => read_stdin()?,
+    };
+
+    Ok(normalise(&input))
+}
+
+// Load puzzle input using the first command line argument as the path
+pub fn from_args() -> io::Result<String> {
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    load(path.as_deref())
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut stdin = io::stdin();
+
+    // Don't wait on a user typing in the puzzle input
+    if stdin.is_terminal() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no input provided, pass a path, set {} or pipe the input to stdin", INPUT_VAR),
+        ));
+    }
+
+    let mut input = String::new();
+    stdin.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+// Use Unix line endings, and drop trailing newlines so the final line isn't followed by an empty one
+pub fn normalise(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_normalises_line_endings() {
+        assert_eq!(normalise("a\r\nb\r\n"), "a\nb");
+    }
+
+    #[test]
+    fn it_normalises_trailing_newlines() {
+        assert_eq!(normalise("a\nb\n\n\n"), "a\nb");
+        assert_eq!(normalise("a\n\nb"), "a\n\nb");
+    }
+
+    #[test]
+    fn it_loads_input_from_path() {
+        let path = env::temp_dir().join(format!("aoc_core_input_{}.txt", std::process::id()));
+        fs::write(&path, "1,2\r\n3,4\r\n").unwrap();
+
+        let input = load(Some(&path));
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(input.unwrap(), "1,2\n3,4");
+    }
+}
@@ -0,0 +1,14 @@
+pub mod answers;
+pub mod coord;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+pub mod search;
+pub mod solution;
+
+pub use coord::{Coord, Direction};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,39 @@
+// Every number is a multiple of 1, so that's the least common multiple of no numbers
+pub fn least_common_multiple(nums: &[usize]) -> usize {
+    nums.iter().fold(1, |lcm, &num| match (lcm, num) {
+        (0, _) | (_, 0) => 0,
+        _ => lcm / greatest_common_divisor(lcm, num) * num,
+    })
+}
+
+// From https://github.com/TheAlgorithms/Rust
+pub fn greatest_common_divisor(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    greatest_common_divisor(b, a % b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_greatest_common_divisor() {
+        assert_eq!(greatest_common_divisor(12, 18), 6);
+        assert_eq!(greatest_common_divisor(7, 13), 1);
+    }
+
+    #[test]
+    fn it_finds_least_common_multiple() {
+        assert_eq!(least_common_multiple(&[2, 3]), 6);
+        assert_eq!(least_common_multiple(&[4, 6, 10]), 60);
+        assert_eq!(least_common_multiple(&[7]), 7);
+        assert_eq!(least_common_multiple(&[3, 0]), 0);
+    }
+
+    #[test]
+    fn it_finds_least_common_multiple_of_no_numbers() {
+        assert_eq!(least_common_multiple(&[]), 1);
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Position of invalid puzzle input
+// Line and column are zero-based indices, as from enumerate, but are displayed one-based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError { line, column, message: message.into() }
+    }
+
+    // Error for a token which has been split out of text, pointing at the token's column
+    pub fn at_token(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        ParseError::new(line, column_of(text, token), message)
+    }
+
+    // Friendly diagnostic, quoting the offending line of input
+    pub fn report(&self, input: &str) -> String {
+        let mut report = format!("error: {}\n --> line {}, column {}", self.message, self.line + 1, self.column + 1);
+
+        if let Some(text) = input.lines().nth(self.line) {
+            let line_number = (self.line + 1).to_string();
+            let gutter = " ".repeat(line_number.len());
+            let chars_before = text.get(..self.column).map_or(self.column, |before| before.chars().count());
+            report += &format!("\n{} |\n{} | {}\n{} | {}^", gutter, line_number, text, gutter, " ".repeat(chars_before));
+        }
+
+        report
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line + 1, self.column + 1, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+// Byte offset of token within text, where token has been split or sliced from text
+// Falls back to the start of the line for unrelated strings
+pub fn column_of(text: &str, token: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+
+    if offset <= text.len() { offset } else { 0 }
+}
+
+// Parse a number which has been split out of a line of input
+pub fn number<T: FromStr>(line: usize, text: &str, token: &str) -> Result<T, ParseError> {
+    let token = token.trim();
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at_token(line, text, token, format!("unable to parse '{}' as a number", token)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_column_of_token() {
+        let text = "Game 12: 3 blue";
+        let token = text.split(' ').nth(2).unwrap();
+        assert_eq!(column_of(text, token), 9);
+        assert_eq!(column_of(text, &String::from("3 blue")), 0);
+    }
+
+    #[test]
+    fn it_parses_numbers() {
+        let text = "Time: 7 x";
+        let mut tokens = text.split_whitespace().skip(1);
+        assert_eq!(number::<i32>(0, text, tokens.next().unwrap()), Ok(7));
+        assert_eq!(
+            number::<i32>(0, text, tokens.next().unwrap()),
+            Err(ParseError::new(0, 8, "unable to parse 'x' as a number"))
+        );
+    }
+
+    #[test]
+    fn it_reports_offending_line() {
+        let error = ParseError::new(1, 2, "invalid card 'X'");
+        let report = error.report("32T3K 765\nT5X5 684");
+        assert_eq!(report, "error: invalid card 'X'\n --> line 2, column 3\n  |\n2 | T5X5 684\n  |   ^");
+    }
+}
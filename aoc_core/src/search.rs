@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Result of searching from the start states, until reaching a goal or running out of states
+// Costs are the cheapest found to each state reached, and the predecessors lead back to a start
+pub struct Search<S, C> {
+    pub costs: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Search { costs: HashMap::new(), predecessors: HashMap::new(), goal: None }
+    }
+
+    // Cost of reaching the goal, None if no goal was reached
+    pub fn cost(&self) -> Option<C> {
+        self.costs.get(self.goal.as_ref()?).copied()
+    }
+
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    // States from a start to state, None if state wasn't reached
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) { return None; };
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Queued state, ordered so that the heap pops the lowest priority first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+// Cheapest path from any start to a goal, where successors gives each next state with the cost of moving to it
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+// Dijkstra, guided towards the goal by heuristic
+// The heuristic must never overestimate the cost to reach a goal, or the path found may not be the cheapest
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        search.costs.insert(start.clone(), C::default());
+        queue.push(Queued { priority: heuristic(&start), cost: C::default(), state: start });
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        // Skip states which have since been reached more cheaply
+        if search.costs.get(&state).is_some_and(|&best| cost > best) { continue; };
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if search.costs.get(&next).is_none_or(|&best| next_cost < best) {
+                search.costs.insert(next.clone(), next_cost);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+
+    search
+}
+
+// Fewest steps from any start to a goal, where every step costs 1
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        search.costs.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let steps = search.costs[&state] + 1;
+        for next in successors(&state) {
+            if search.costs.contains_key(&next) { continue; };
+            search.costs.insert(next.clone(), steps);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b is cheapest directly, but a -> c -> d is cheaper than a -> b -> d
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2)],
+            'b' => vec![('d', 5)],
+            'c' => vec![('d', 1), ('b', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn it_finds_the_cheapest_path() {
+        let search = dijkstra(['a'], edges, |&node| node == 'd');
+        assert_eq!(search.cost(), Some(3));
+        assert_eq!(search.path(), Some(vec!['a', 'c', 'd']));
+        assert_eq!(search.path_to(&'b'), Some(vec!['a', 'b']));
+        assert_eq!(search.path_to(&'e'), None);
+    }
+
+    #[test]
+    fn it_searches_every_state_without_a_goal() {
+        let search = dijkstra(['a'], edges, |_| false);
+        assert_eq!(search.cost(), None);
+        assert_eq!(search.costs.len(), 4);
+        assert_eq!(search.costs[&'d'], 3);
+    }
+
+    #[test]
+    fn it_finds_the_cheapest_path_with_a_heuristic() {
+        let neighbours = |&(x, y): &(i32, i32)| [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)].map(|next| (next, 1));
+        let distance = |&(x, y): &(i32, i32)| (5 - x).abs() + (3 - y).abs();
+
+        let search = astar([(0, 0)], neighbours, distance, |&coord| coord == (5, 3));
+        assert_eq!(search.cost(), Some(8));
+        assert_eq!(search.path().unwrap().len(), 9);
+    }
+
+    #[test]
+    fn it_counts_fewest_steps() {
+        let search = bfs([1], |&n: &u32| [n + 1, n * 2].into_iter().filter(|&next| next <= 20), |&n| n == 20);
+        assert_eq!(search.cost(), Some(5));
+        assert_eq!(search.path(), Some(vec![1, 2, 4, 5, 10, 20]));
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::ParseError;
+
+// Answers are stored as strings as each day uses its own numeric type
+// Parsing and solving are timed separately, so slow parsers stand out in benchmarks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.answer)
+    }
+}
+
+pub type Answer = Result<Output, ParseError>;
+
+// Common interface for each day, so the runner can solve any day and part
+pub trait Solution {
+    fn part1(&self, input: &str) -> Answer;
+
+    // Not every day has a solution for part 2 yet
+    fn part2(&self, _input: &str) -> Option<Answer> {
+        None
+    }
+
+    // Solve part 1 or 2, returns None if the part has not been solved yet
+    fn solve(&self, part: u8, input: &str) -> Option<Answer> {
+        match part {
+            1 => Some(self.part1(input)),
+            _ => self.part2(input),
+        }
+    }
+}
+
+// Parse the input, then solve the puzzle from the parsed input, timing each step
+pub fn timed<'a, P, T: ToString>(
+    input: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<P, ParseError>,
+    solve: impl FnOnce(P) -> T,
+) -> Answer {
+    try_timed(input, parse, |parsed| Ok(solve(parsed)))
+}
+
+// As timed, for puzzles where input which parses can still have no answer
+pub fn try_timed<'a, P, T: ToString>(
+    input: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<P, ParseError>,
+    solve: impl FnOnce(P) -> Result<T, ParseError>,
+) -> Answer {
+    let start = Instant::now();
+    let parsed = parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(parsed)?.to_string();
+    let solve_time = start.elapsed();
+
+    Ok(Output { answer, parse_time, solve_time })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_times_parsing_and_solving() {
+        let output = timed("1,2,3", |input| Ok(input.split(',').count()), |count| count * 2).unwrap();
+        assert_eq!(output.answer, "6");
+        assert_eq!(output.to_string(), "6");
+    }
+
+    #[test]
+    fn it_does_not_solve_invalid_input() {
+        let error = ParseError::new(0, 0, "invalid");
+        let output = timed("", |_| Err::<usize, _>(error.clone()), |count| count);
+        assert_eq!(output, Err(error));
+    }
+
+    #[test]
+    fn it_reports_input_without_an_answer() {
+        let error = ParseError::new(0, 2, "no answer");
+        let output = try_timed("1,2", |input| Ok(input.len()), |_| Err::<usize, _>(error.clone()));
+        assert_eq!(output, Err(error));
+    }
+}
@@ -0,0 +1,18 @@
+use aoc_core::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn part1(&self, input: &str) -> Answer {
+        part1::answer(input)
+    }
+
+    fn part2(&self, input: &str) -> Option<Answer> {
+        Some(part2::answer(input))
+    }
+}
+
+aoc_core::examples!(Day01);
@@ -0,0 +1,36 @@
+use aoc_core::{solution, Answer, ParseError};
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut calibration_values: Vec<i32> = vec![];
+
+    for (line_index, line) in input.lines().enumerate() {
+        let mut first_and_last: Vec<String> = vec![];
+
+        let numeric_chars: Vec<char> = line
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+
+        let no_digits = || ParseError::new(line_index, 0, "no digits found in line");
+        first_and_last.push(numeric_chars.first().ok_or_else(no_digits)?.to_string());
+        first_and_last.push(numeric_chars.last().ok_or_else(no_digits)?.to_string());
+
+        let value: i32 = first_and_last.join("").parse::<i32>().map_err(|_| no_digits())?;
+
+        calibration_values.push(value);
+    }
+
+    Ok(calibration_values)
+}
+
+fn solve(calibration_values: Vec<i32>) -> i32 {
+    calibration_values.iter().sum()
+}
@@ -0,0 +1,75 @@
+use aoc_core::{solution, Answer, ParseError};
+use regex::Regex;
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut calibration_values: Vec<i32> = vec![];
+
+    let re = Regex::new(r"[1-9]|one|two|three|four|five|six|seven|eight|nine").unwrap();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let mut first_and_last: Vec<&str> = Vec::new();
+        let matches: Vec<(&str, usize)> = re.find_iter(line).map(|m| (m.as_str(), m.start())).collect();
+
+        let no_digits = || ParseError::new(line_index, 0, "no digits found in line");
+
+        let (first, _) = matches.first().ok_or_else(no_digits)?;
+        first_and_last.push(first);
+        
+        // Check for overlapping matches in remaining substring
+        // Regex doesn't pick up overlaps
+        let (last, last_index) = matches.last().ok_or_else(no_digits)?;
+        let overlapping_match = re.find(&line[(last_index + 1)..]).map(|m| m.as_str());
+
+        if let Some(overlapping_match) = overlapping_match {
+          first_and_last.push(overlapping_match);
+        } else {
+          first_and_last.push(last);
+        };
+
+        // convert first and last to number
+        first_and_last = first_and_last.iter().map(|&digit| {
+          match digit {
+            "one" => "1",
+            "two" => "2",
+            "three" => "3",
+            "four" => "4",
+            "five" => "5",
+            "six" => "6",
+            "seven" => "7",
+            "eight" => "8",
+            "nine" => "9",
+            _ => digit,
+          }
+        }).collect();
+
+        let value: i32 = first_and_last.join("").parse::<i32>().map_err(|_| no_digits())?;
+
+        calibration_values.push(value);
+    }
+
+    Ok(calibration_values)
+}
+
+fn solve(calibration_values: Vec<i32>) -> i32 {
+    calibration_values.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_handle_overlapping_numbers() {
+      let input = "five2jzsconeightm";
+      let result = part2(input).unwrap();
+      assert_eq!(result, 58);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use aoc_core::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn part1(&self, input: &str) -> Answer {
+        part1::answer(input)
+    }
+
+    fn part2(&self, input: &str) -> Option<Answer> {
+        Some(part2::answer(input))
+    }
+}
+
+aoc_core::examples!(Day02);
@@ -0,0 +1,88 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+const MAX_RED: i32 = 12;
+const MAX_GREEN: i32 = 13;
+const MAX_BLUE: i32 = 14;
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+  parse_games(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+  solution::timed(input, parse_games, solve)
+}
+
+fn solve(games: Vec<Game>) -> i32 {
+  let playable_games = games.iter().filter(|&game| is_playable(game));
+  playable_games.map(|game| game.id).sum()
+}
+
+struct Game {
+  id: i32,
+  max_red: i32,
+  max_green: i32,
+  max_blue: i32,
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+  let mut games: Vec<Game> = Vec::new();
+  for (line_index, line) in input.lines().enumerate() {
+    let (id, hands) = line
+      .split_once(":")
+      .ok_or(ParseError::new(line_index, 0, "missing ':' after game id"))?;
+    let id = parse::number::<i32>(line_index, line, id.trim().trim_start_matches("Game"))?;
+
+    let mut red_balls: Vec<i32> = Vec::new();
+    let mut green_balls: Vec<i32> = Vec::new();
+    let mut blue_balls: Vec<i32> = Vec::new();
+
+    let hands: Vec<Vec<&str>> = hands.split(";").map(|game| game.split(", ").collect()).collect();
+  
+    for hand in hands {
+      for ball_colour in hand {
+        match ball_colour {
+          red if red.ends_with(" red") => 
+            red_balls.push(parse::number(line_index, line, red.trim_end_matches(" red"))?),
+          green if green.ends_with(" green") => 
+            green_balls.push(parse::number(line_index, line, green.trim_end_matches(" green"))?),
+          blue if blue.ends_with(" blue") => 
+            blue_balls.push(parse::number(line_index, line, blue.trim_end_matches(" blue"))?),
+          _ => {
+            let ball_colour = ball_colour.trim();
+            return Err(ParseError::at_token(line_index, line, ball_colour, format!("unknown ball colour '{}'", ball_colour)));
+          },
+        }
+      }
+    }
+  
+    games.push(Game {
+      id,
+      max_red: if red_balls.is_empty() { 0 } else { *red_balls.iter().max().unwrap() },
+      max_green: if green_balls.is_empty() { 0 } else { *green_balls.iter().max().unwrap() },
+      max_blue: if blue_balls.is_empty() { 0 } else { *blue_balls.iter().max().unwrap() },
+    });
+
+  }
+  Ok(games)
+}
+
+fn is_playable(game: &Game) -> bool {
+  if game.max_red > MAX_RED { return false; }
+  if game.max_green > MAX_GREEN { return false; }
+  if game.max_blue > MAX_BLUE { return false; }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_unknown_ball_colour() {
+        let input = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(1, 16, "unknown ball colour '2 purple'")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+  parse_games(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+  solution::timed(input, parse_games, solve)
+}
+
+fn solve(games: Vec<Game>) -> i32 {
+  games.iter().map(calculate_game_power).sum()
+}
+
+struct Game {
+  max_red: i32,
+  max_green: i32,
+  max_blue: i32,
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+  let mut games: Vec<Game> = Vec::new();
+  for (line_index, line) in input.lines().enumerate() {
+    let (_, hands) = line
+      .split_once(":")
+      .ok_or(ParseError::new(line_index, 0, "missing ':' after game id"))?;
+
+    let mut red_balls: Vec<i32> = Vec::new();
+    let mut green_balls: Vec<i32> = Vec::new();
+    let mut blue_balls: Vec<i32> = Vec::new();
+
+    let hands: Vec<Vec<&str>> = hands.split(";").map(|game| game.split(", ").collect()).collect();
+  
+    for hand in hands {
+      for ball_colour in hand {
+        match ball_colour {
+          red if red.ends_with(" red") => 
+            red_balls.push(parse::number(line_index, line, red.trim_end_matches(" red"))?),
+          green if green.ends_with(" green") => 
+            green_balls.push(parse::number(line_index, line, green.trim_end_matches(" green"))?),
+          blue if blue.ends_with(" blue") => 
+            blue_balls.push(parse::number(line_index, line, blue.trim_end_matches(" blue"))?),
+          _ => {
+            let ball_colour = ball_colour.trim();
+            return Err(ParseError::at_token(line_index, line, ball_colour, format!("unknown ball colour '{}'", ball_colour)));
+          },
+        }
+      }
+    }
+  
+    games.push(Game {
+      max_red: if red_balls.is_empty() { 0 } else { *red_balls.iter().max().unwrap() },
+      max_green: if green_balls.is_empty() { 0 } else { *green_balls.iter().max().unwrap() },
+      max_blue: if blue_balls.is_empty() { 0 } else { *blue_balls.iter().max().unwrap() },
+    });
+
+  }
+  Ok(games)
+}
+
+fn calculate_game_power(game: &Game) -> i32 {
+  game.max_red * game.max_green * game.max_blue
+}
@@ -0,0 +1,94 @@
+use aoc_core::{solution, Answer, ParseError};
+use regex::Regex;
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+// Find every part number adjacent to a symbol
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let input_lines: Vec<&str> = input.lines().collect();
+    let symbol_re = Regex::new(r"[^0-9.]").unwrap();
+    let number_re = Regex::new(r"[0-9]+").unwrap();
+
+    let mut parts: Vec<i32> = Vec::new();
+
+    for (line_index, line) in input_lines.iter().enumerate() {
+        let symbols = symbol_re.find_iter(line);
+        let is_first_line = line_index == 0;
+        let is_final_line = line_index == input_lines.len() - 1;
+
+        for symbol in symbols.into_iter() {
+            let symbol_index = symbol.start();
+ 
+            if !is_first_line {
+                let line_above = number_re.find_iter(input_lines[line_index - 1]);
+                check_line_above(line_above, line_index - 1, symbol_index, &mut parts)?;
+            }
+            
+            let current_line = number_re.find_iter(input_lines[line_index]);
+            check_current_line(current_line, line_index, symbol_index, &mut parts)?;
+
+            if !is_final_line {
+                let line_below = number_re.find_iter(input_lines[line_index + 1]);
+                check_line_below(line_below, line_index + 1, symbol_index, &mut parts)?;
+            }
+                
+        }
+    }
+    Ok(parts)
+}
+
+fn solve(parts: Vec<i32>) -> i32 {
+    parts.iter().sum()
+}
+
+fn check_line_above(line_above: regex::Matches<'_, '_>, line_index: usize, symbol_index: usize, parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in line_above {
+        let start = if num.start() == 0 { 0 } else { num.start() - 1 };
+        let end = num.end() + 1;
+        let range = start..end;
+        if range.contains(&symbol_index) {
+            let part = parse_part(line_index, num)?;
+            parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn check_current_line(current_line: regex::Matches<'_, '_>, line_index: usize, symbol_index: usize, parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in current_line {
+        let is_touching = num.end() == symbol_index || (num.start() > 0 && num.start() - 1 == symbol_index);
+        if is_touching {
+            let part = parse_part(line_index, num)?;
+            parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn check_line_below(line_below: regex::Matches<'_, '_>, line_index: usize, symbol_index: usize, parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in line_below {
+        let start = if num.start() == 0 { 0 } else { num.start() - 1 };
+        let end = num.end() + 1;
+        let range = start..end;
+        if range.contains(&symbol_index) {
+            let part = parse_part(line_index, num)?;
+            parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_part(line_index: usize, num: regex::Match) -> Result<i32, ParseError> {
+    num.as_str()
+        .parse::<i32>()
+        .map_err(|_| ParseError::new(line_index, num.start(), format!("part number '{}' is too large", num.as_str())))
+}
@@ -0,0 +1,102 @@
+use aoc_core::{solution, Answer, ParseError};
+use regex::Regex;
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+// Find the part numbers adjacent to each gear
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let input_lines: Vec<&str> = input.lines().collect();
+    let gear_re = Regex::new(r"[*]").unwrap();
+    let number_re = Regex::new(r"[0-9]+").unwrap();
+
+    let mut gears_parts: Vec<Vec<i32>> = Vec::new();
+
+    for (line_index, line) in input_lines.iter().enumerate() {
+        let gears = gear_re.find_iter(line);
+        let is_first_line = line_index == 0;
+        let is_final_line = line_index == input_lines.len() - 1;
+        
+        for gear in gears.into_iter() {
+            let mut adjoining_parts: Vec<i32> = Vec::new();
+            let gear_index = gear.start();
+
+            if !is_first_line {
+                let line_above = number_re.find_iter(input_lines[line_index - 1]);
+                check_line_above(line_above, line_index - 1, gear_index, &mut adjoining_parts)?;
+            }
+            
+            let current_line = number_re.find_iter(input_lines[line_index]);
+            check_current_line(current_line, line_index, gear_index, &mut adjoining_parts)?;
+
+            if !is_final_line {
+                let line_below = number_re.find_iter(input_lines[line_index + 1]);
+                check_line_below(line_below, line_index + 1, gear_index, &mut adjoining_parts)?;
+            }
+
+            gears_parts.push(adjoining_parts);
+        }
+    }
+
+    Ok(gears_parts)
+}
+
+fn solve(gears_parts: Vec<Vec<i32>>) -> i32 {
+    gears_parts
+        .iter()
+        // Only count gears with exactly two adjoining parts
+        .filter(|adjoining_parts| adjoining_parts.len() == 2)
+        .map(|adjoining_parts| adjoining_parts[0] * adjoining_parts[1])
+        .sum()
+}
+
+fn check_line_above(line_above: regex::Matches<'_, '_>, line_index: usize, gear_index: usize, adjoining_parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in line_above {
+        let start = if num.start() == 0 { 0 } else { num.start() - 1 };
+        let end = num.end() + 1;
+        let range = start..end;
+        if range.contains(&gear_index) {
+            let part = parse_part(line_index, num)?;
+            adjoining_parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn check_current_line(current_line: regex::Matches<'_, '_>, line_index: usize, gear_index: usize, adjoining_parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in current_line {
+        let is_touching = num.end() == gear_index || (num.start() > 0 && num.start() - 1 == gear_index);
+        if is_touching {
+            let part = parse_part(line_index, num)?;
+            adjoining_parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn check_line_below(line_below: regex::Matches<'_, '_>, line_index: usize, gear_index: usize, adjoining_parts: &mut Vec<i32>) -> Result<(), ParseError> {
+    for num in line_below {
+        let start = if num.start() == 0 { 0 } else { num.start() - 1 };
+        let end = num.end() + 1;
+        let range = start..end;
+        if range.contains(&gear_index) {
+            let part = parse_part(line_index, num)?;
+            adjoining_parts.push(part)
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_part(line_index: usize, num: regex::Match) -> Result<i32, ParseError> {
+    num.as_str()
+        .parse::<i32>()
+        .map_err(|_| ParseError::new(line_index, num.start(), format!("part number '{}' is too large", num.as_str())))
+}
@@ -0,0 +1,58 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+type Numbers = Vec<Vec<i32>>;
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve((results, cards): (Numbers, Numbers)) -> i32 {
+    let mut points: Vec<i32> = Vec::new();
+
+    for (i, result) in results.iter().enumerate() {
+      let mut match_count = 0;
+      let mut card_points: i32 = 0;
+      let card = &cards[i];
+      for num in result {
+        if card.contains(num) {
+          match match_count {
+            0 => card_points += 1,
+            _ => card_points *= 2,
+          };
+          match_count += 1;
+        }
+      }
+      points.push(card_points);
+    };
+
+    points.iter().sum()
+}
+
+fn parse_input(input: &str) -> Result<(Numbers, Numbers), ParseError> {
+    let mut results: Numbers = Vec::new();
+    let mut cards: Numbers = Vec::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+      let (_, numbers) = line
+        .split_once(":")
+        .ok_or(ParseError::new(line_index, 0, "missing ':' after card id"))?;
+      let (result, card) = numbers
+        .split_once("|")
+        .ok_or(ParseError::at_token(line_index, line, numbers, "missing '|' between winning numbers and card"))?;
+      results.push(parse_card_and_result(line_index, line, result)?);
+      cards.push(parse_card_and_result(line_index, line, card)?);
+    }
+
+    Ok((results, cards))
+}
+
+fn parse_card_and_result(line_index: usize, line: &str, input: &str) -> Result<Vec<i32>, ParseError> {
+  input
+    .split_whitespace()
+    .map(|r| parse::number(line_index, line, r))
+    .collect()
+}
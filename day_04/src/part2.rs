@@ -0,0 +1,59 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+type Numbers = Vec<Vec<i32>>;
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve((results, cards): (Numbers, Numbers)) -> i32 {
+    let mut won_cards: Vec<i32> = vec![1; cards.len()];
+
+    for (i, result) in results.iter().enumerate() {
+      let mut match_count = 0;
+      let card = &cards[i];
+      // Count number of matching results
+      result.iter().filter(|num| card.contains(num)).for_each(|_| match_count += 1);
+
+      // Count number of winning cards
+      // Each matching result wins an additional subsequent card
+      for j in 0..match_count {
+        let win_index = i + j + 1;
+        if win_index <= results.len() {
+          won_cards[win_index] += won_cards[i];
+        };
+      };
+
+    };
+
+    won_cards.iter().filter(|card| **card != 0).sum()
+}
+
+fn parse_input(input: &str) -> Result<(Numbers, Numbers), ParseError> {
+    let mut results: Numbers = Vec::new();
+    let mut cards: Numbers = Vec::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+      let (_, numbers) = line
+        .split_once(":")
+        .ok_or(ParseError::new(line_index, 0, "missing ':' after card id"))?;
+      let (result, card) = numbers
+        .split_once("|")
+        .ok_or(ParseError::at_token(line_index, line, numbers, "missing '|' between winning numbers and card"))?;
+      results.push(parse_card_and_result(line_index, line, result)?);
+      cards.push(parse_card_and_result(line_index, line, card)?);
+    }
+
+    Ok((results, cards))
+}
+
+fn parse_card_and_result(line_index: usize, line: &str, input: &str) -> Result<Vec<i32>, ParseError> {
+  input
+    .split_whitespace()
+    .map(|r| parse::number(line_index, line, r))
+    .collect()
+}
@@ -0,0 +1,95 @@
+use std::ops::Range;
+use aoc_core::{parse, solution, Answer, ParseError};
+
+type Seeds = Vec<i64>;
+
+struct PlantingMap {
+  destination: Range<i64>,
+  source: Range<i64>,
+}
+
+type Almanac = Vec<Vec<PlantingMap>>;
+
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve((seeds, almanac): (Seeds, Almanac)) -> i64 {
+    let locations = traverse_almanac(seeds, almanac);
+    *locations.iter().min().expect("Unable to find min value")
+}
+
+fn parse_input(input: &str) -> Result<(Seeds, Almanac), ParseError> {
+  let mut lines = input.lines().enumerate();
+  let (_, seeds_line) = lines.next().ok_or(ParseError::new(0, 0, "missing seeds"))?;
+
+  let seeds: Seeds = seeds_line
+    .strip_prefix("seeds:")
+    .ok_or(ParseError::new(0, 0, "expected line to start with 'seeds:'"))?
+    .split_whitespace()
+    .map(|s| parse::number(0, seeds_line, s))
+    .collect::<Result<_, _>>()?;
+
+  if seeds.is_empty() { return Err(ParseError::new(0, 0, "no seeds found")); };
+
+  let mut almanac: Almanac = Vec::new();
+
+  for (line_index, line) in lines {
+    // Skip empty lines between almanac sections
+    if line.trim().is_empty() { continue; };
+
+    // Text line starts a new almanac section
+    if line.ends_with("map:") {
+      almanac.push(Vec::new());
+      continue;
+    };
+
+    let values: Vec<i64> = line
+      .split_whitespace()
+      .map(|str| parse::number(line_index, line, str))
+      .collect::<Result<_, _>>()?;
+
+    let [destination_val, source_val, range] = values[..] else {
+      return Err(ParseError::new(line_index, 0, "expected destination, source and range"));
+    };
+
+    let table = almanac
+      .last_mut()
+      .ok_or(ParseError::new(line_index, 0, "expected a map heading before values"))?;
+
+    table.push(PlantingMap { 
+      source: source_val..source_val + range, 
+      destination: destination_val..destination_val + range 
+    });
+  }
+
+  Ok((seeds, almanac))
+}
+
+fn traverse_almanac(seeds: Seeds, almanac: Almanac) -> Vec<i64> {
+  let locations: Vec<i64> = seeds.iter().map(|seed| {
+    // Set initial value as default
+    let mut current_value = *seed;
+
+    // Traverse almanac tables
+    almanac.iter().for_each(|table| {
+      'table: for planting_map in table {
+        // Check each planting map
+        if planting_map.source.contains(&current_value) {
+          // Map incoming value from source to destination
+          let diff = current_value - planting_map.source.start;
+          current_value = planting_map.destination.start + diff;
+          break 'table;
+        }
+      }
+    });
+
+    current_value
+  }).collect();
+
+  locations
+}
@@ -0,0 +1,146 @@
+use std::ops::Range;
+use aoc_core::{parse, solution, Answer, ParseError};
+use std::thread;
+
+type SeedRanges = Vec<Range<i64>>;
+
+#[derive(Clone)]
+struct PlantingMap {
+  destination: Range<i64>,
+  source: Range<i64>,
+}
+
+type Almanac = Vec<Vec<PlantingMap>>;
+
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve((seed_ranges, almanac): (SeedRanges, Almanac)) -> i64 {
+    let nearest_locations = traverse_seed_ranges(seed_ranges, almanac);
+    // Parsing rejects empty seed ranges, so there is always a nearest location
+    *nearest_locations.iter().min().expect("Unable to find min value")
+}
+
+fn traverse_seed_ranges(seed_ranges: Vec<Range<i64>>, almanac: Vec<Vec<PlantingMap>>) -> Vec<i64> {
+    let mut handles: Vec<thread::JoinHandle<i64>> = Vec::new();
+
+    for range in seed_ranges {
+      // Clone almanac for each thread
+      let almanac_clone = almanac.clone();
+
+      // Open a new thread for each range
+      // Map one seed at a time, rather than collecting every seed and location in the range
+      let handle = thread::spawn(move || {
+        range
+          .map(|seed| traverse_almanac(seed, &almanac_clone))
+          .min()
+          .expect("Unable to find min value")
+      });
+
+      handles.push(handle);
+    }
+    // Wait for all threads to complete, then join results
+    let nearest_locations: Vec<i64> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+    nearest_locations
+}
+
+fn parse_input(input: &str) -> Result<(SeedRanges, Almanac), ParseError> {
+  let mut lines = input.lines().enumerate();
+  let (_, seeds_line) = lines.next().ok_or(ParseError::new(0, 0, "missing seeds"))?;
+
+  let seeds: Vec<&str> = seeds_line
+    .strip_prefix("seeds:")
+    .ok_or(ParseError::new(0, 0, "expected line to start with 'seeds:'"))?
+    .split_whitespace()
+    .collect();
+
+  if seeds.is_empty() { return Err(ParseError::new(0, 0, "no seeds found")); };
+
+  if !seeds.len().is_multiple_of(2) {
+    return Err(ParseError::new(0, 0, "expected seeds to be pairs of start and range"));
+  };
+
+  let seed_ranges: SeedRanges = seeds
+    .chunks(2)
+    .map(|item| {
+        let start: i64 = parse::number(0, seeds_line, item[0])?;
+        let range: i64 = parse::number(0, seeds_line, item[1])?;
+
+        // An empty range has no seeds, so no nearest location
+        if range <= 0 {
+          return Err(ParseError::at_token(0, seeds_line, item[1], "expected seed range to be at least 1"));
+        };
+
+        Ok(start..start + range)
+    })
+    .collect::<Result<_, _>>()?;
+
+  let mut almanac: Almanac = Vec::new();
+
+  for (line_index, line) in lines {
+    // Skip empty lines between almanac sections
+    if line.trim().is_empty() { continue; };
+
+    // Text line starts a new almanac section
+    if line.ends_with("map:") {
+      almanac.push(Vec::new());
+      continue;
+    };
+
+    let values: Vec<i64> = line
+      .split_whitespace()
+      .map(|str| parse::number(line_index, line, str))
+      .collect::<Result<_, _>>()?;
+
+    let [destination_val, source_val, range] = values[..] else {
+      return Err(ParseError::new(line_index, 0, "expected destination, source and range"));
+    };
+
+    let table = almanac
+      .last_mut()
+      .ok_or(ParseError::new(line_index, 0, "expected a map heading before values"))?;
+
+    table.push(PlantingMap { 
+      source: source_val..source_val + range, 
+      destination: destination_val..destination_val + range 
+    });
+  }
+
+  Ok((seed_ranges, almanac))
+}
+
+fn traverse_almanac(seed: i64, almanac: &Almanac) -> i64 {
+  // Set initial value as default
+  let mut current_value = seed;
+
+  // Traverse almanac tables
+  almanac.iter().for_each(|table| {
+    'table: for planting_map in table {
+      // Check each planting map
+      if planting_map.source.contains(&current_value) {
+        // Map incoming value from source to destination
+        let diff = current_value - planting_map.source.start;
+        current_value = planting_map.destination.start + diff;
+        break 'table;
+      }
+    }
+  });
+
+  current_value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_empty_seed_ranges() {
+        assert_eq!(part2("seeds: 1 0"), Err(ParseError::new(0, 9, "expected seed range to be at least 1")));
+    }
+}
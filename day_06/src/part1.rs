@@ -0,0 +1,70 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+use regex::Regex;
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve(races: Vec<(i32, i32)>) -> i32 {
+    let mut results: Vec<i32> = Vec::new();
+
+    for (time, distance) in races {
+        results.push(calculate_ways_to_win_race(time, distance));
+    };
+
+    results.iter().product()
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let times: Vec<i32> = parse_line(input, 0, "Time:")?;
+    let distances: Vec<i32> = parse_line(input, 1, "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::new(1, 0, format!("expected {} distances, found {}", times.len(), distances.len())));
+    }
+
+    let races: Vec<(i32, i32)> = (0..times.len())
+        .map(|i| (times[i], distances[i]))
+        .collect();
+
+    Ok(races)
+}
+
+fn parse_line(input: &str, line_index: usize, label: &str) -> Result<Vec<i32>, ParseError> {
+    let re = Regex::new(r"[0-9]+").unwrap();
+    let line = input
+        .lines()
+        .nth(line_index)
+        .filter(|line| line.starts_with(label))
+        .ok_or(ParseError::new(line_index, 0, format!("expected line starting '{}'", label)))?;
+
+    re.find_iter(line)
+        .map(|m| parse::number(line_index, line, m.as_str()))
+        .collect()
+}
+
+fn calculate_ways_to_win_race(time: i32, total_distance: i32) -> i32 {
+    let mut count: i32 = 0;
+    (1..time - 1).for_each(|mps: i32| {
+        let distance_covered = mps * (time - mps);
+        if distance_covered > total_distance { count += 1; }
+    });
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_missing_distances() {
+        let input = "Time:      7  15   30";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(1, 0, "expected line starting 'Distance:'")));
+    }
+}
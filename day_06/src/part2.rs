@@ -0,0 +1,43 @@
+use aoc_core::{solution, Answer, ParseError};
+
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve((time, distance): (i64, i64)) -> i64 {
+    calculate_ways_to_win_race(time, distance)
+}
+
+fn parse_input(input: &str) -> Result<(i64, i64), ParseError> {
+    let time: i64 = parse_line(input, 0, "Time:")?;
+    let distance: i64 = parse_line(input, 1, "Distance:")?;
+
+    Ok((time, distance))
+}
+
+// Kerning is ignored, so all digits on the line make up a single number
+fn parse_line(input: &str, line_index: usize, label: &str) -> Result<i64, ParseError> {
+    let line = input.lines().nth(line_index).unwrap_or_default();
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or(ParseError::new(line_index, 0, format!("expected line starting '{}'", label)))?;
+
+    let number: String = numbers.replace(' ', "");
+    number
+        .parse::<i64>()
+        .map_err(|_| ParseError::at_token(line_index, line, numbers.trim_start(), format!("unable to parse '{}' as a number", number)))
+}
+
+fn calculate_ways_to_win_race(time: i64, total_distance: i64) -> i64 {
+    let mut count: i64 = 0;
+    (1..time - 1).for_each(|mps: i64| {
+        let distance_covered = mps * (time - mps);
+        if distance_covered > total_distance { count += 1; }
+    });
+
+    count
+}
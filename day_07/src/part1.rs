@@ -0,0 +1,164 @@
+use std::{collections::HashMap, cmp::Ordering};
+
+use aoc_core::{parse, solution, Answer, ParseError};
+
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+enum HandType {
+    FiveOfAKind = 7,
+    FourOfAKind = 6,
+    FullHouse = 5,
+    ThreeOfAKind = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1,
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
+enum Card {
+    A = 14, 
+    K = 13, 
+    Q = 12, 
+    J = 11, 
+    T = 10, 
+    Nine = 9, 
+    Eight = 8,
+    Seven = 7,
+    Six = 6,
+    Five = 5,
+    Four = 4,
+    Three = 3,
+    Two = 2,
+}
+
+type Bid = i32;
+
+type Hand = Vec<Card>;
+
+type Hands = Vec<(Hand, Bid, HandType)>;
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve(mut hands: Hands) -> usize {
+    hands.sort_by(|(cards_a, _, hand_type_a), (cards_b, _, hand_type_b) | {
+        match hand_type_a.cmp(hand_type_b) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => compare_cards(cards_a, cards_b),
+        }
+    });
+    let score: usize = hands
+        .iter()
+        .enumerate()
+        // Multiply rank by bid
+        .map(|(i, (_, bid, _))| (i + 1) * *bid as usize)
+        .sum();
+
+    score
+}
+
+fn compare_cards(a: &[Card], b: &[Card]) -> Ordering {
+    for (card_a, card_b) in a.iter().zip(b.iter()) {
+        match card_a.cmp(card_b) {
+            Ordering::Less => return Ordering::Less,
+            Ordering::Greater => return Ordering::Greater,
+            Ordering::Equal => continue,
+        }
+    }
+    
+    Ordering::Equal
+}
+
+fn parse_input(input: &str) -> Result<Hands, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (hand, bid) = line
+                .split_once(' ')
+                .ok_or(ParseError::new(line_index, line.len(), "expected a hand followed by a bid"))?;
+            let hand = parse_hand(line_index, line, hand)?;
+            let bid: Bid = parse::number(line_index, line, bid)?;
+            let hand_type = calculate_hand_type(&hand);
+            Ok((hand, bid, hand_type))
+        })
+        .collect()
+}
+
+fn parse_hand(line_index: usize, line: &str, hand: &str) -> Result<Hand, ParseError> {
+    if hand.chars().count() != 5 {
+        return Err(ParseError::new(line_index, 0, format!("expected 5 cards in hand '{}'", hand)));
+    }
+
+    hand.chars()
+        .enumerate()
+        .map(|(column, char)| {
+            char_to_card(char).ok_or(ParseError::new(line_index, parse::column_of(line, hand) + column, format!("invalid card '{}'", char)))
+        })
+        .collect()
+}
+
+fn calculate_hand_type(hand: &Hand) -> HandType {
+    let mut counts_map = HashMap::new();
+
+    // Count instances of each card in a HashMap
+    hand.iter().for_each(|card| { 
+        counts_map.entry(card)
+            .and_modify(|count| { *count += 1 })
+            .or_insert(1); 
+    });
+
+    // Get counts and sort highest -> lowest
+    let mut counts: Vec<i32> = counts_map.into_values().collect();
+    counts.sort_by_key(|&count| std::cmp::Reverse(count));
+
+    // Pattern match card count -> HandType
+    match counts[..] {
+        [5] => HandType::FiveOfAKind,
+        [4, ..] => HandType::FourOfAKind,
+        [3, 2] => HandType::FullHouse,
+        [3, ..] => HandType::ThreeOfAKind,
+        [2, 2, ..] => HandType::TwoPair,
+        [2, 1, ..] => HandType::OnePair,
+        [1, ..] => HandType::HighCard,
+        _ => panic!("Invalid hand, something went wrong!"),
+    }
+}
+
+fn char_to_card(char: char) -> Option<Card> {
+    let card = match char {
+        'A' => Card::A, 
+        'K'  => Card::K,
+        'Q' => Card::Q,
+        'J' => Card::J,
+        'T' => Card::T,
+        '9' => Card::Nine,
+        '8' => Card::Eight,
+        '7' => Card::Seven,
+        '6' => Card::Six,
+        '5' => Card::Five,
+        '4' => Card::Four,
+        '3' => Card::Three,
+        '2' => Card::Two,
+        _ => return None,
+    };
+    Some(card)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_invalid_cards() {
+        let input = "32T3K 765
+T5X5J 684";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(1, 2, "invalid card 'X'")));
+    }
+}
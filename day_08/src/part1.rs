@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use aoc_core::{search, solution, Answer, ParseError};
+use regex::Regex;
+
+enum Direction {
+    L,
+    R,
+}
+
+type Path = Vec<Direction>;
+
+type Graph = HashMap<String, Node>;
+
+struct Node {
+    id: String,
+    left: String,
+    right: String,
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    parse_input(input).and_then(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::try_timed(input, parse_input, solve)
+}
+
+fn solve((path, graph): (Path, Graph)) -> Result<usize, ParseError> {
+    traverse_graph(&path, &graph).ok_or(ParseError::new(0, 0, "ZZZ is unreachable from AAA"))
+}
+
+fn parse_input(input: &str) -> Result<(Path, Graph), ParseError> {
+    let path: Path = input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .enumerate()
+        .map(|(column, char)| match char {
+            'L' => Ok(Direction::L),
+            'R' => Ok(Direction::R),
+            _ => Err(ParseError::new(0, column, format!("invalid direction '{}'", char))),
+        })
+        .collect::<Result<Path, ParseError>>()?;
+
+    if path.is_empty() {
+        return Err(ParseError::new(0, 0, "expected a list of directions"));
+    }
+
+    let re = Regex::new(r"^([A-Z]{3}) = \(([A-Z]{3}), ([A-Z]{3})\)$").unwrap();
+    let mut graph: Graph = Graph::new();
+    for (line_index, line) in input.lines().enumerate().skip(2) {
+        let captures = re
+            .captures(line)
+            .ok_or(ParseError::new(line_index, 0, "expected a node in the format 'AAA = (BBB, CCC)'"))?;
+        let id = captures[1].to_string();
+        let left = captures[2].to_string();
+        let right = captures[3].to_string();
+
+        graph.insert(id.clone(), Node { id, left, right });
+    }
+
+    // Check every node leads somewhere, so the graph can be traversed without failing
+    for (line_index, line) in input.lines().enumerate().skip(2) {
+        let captures = re.captures(line).unwrap();
+        for neighbour in [captures.get(2).unwrap(), captures.get(3).unwrap()] {
+            if !graph.contains_key(neighbour.as_str()) {
+                return Err(ParseError::new(line_index, neighbour.start(), format!("unknown node '{}'", neighbour.as_str())));
+            }
+        }
+    }
+
+    if !graph.contains_key("AAA") {
+        return Err(ParseError::new(0, 0, "no node 'AAA' to start from"));
+    }
+
+    if !graph.contains_key("ZZZ") {
+        return Err(ParseError::new(0, 0, "no node 'ZZZ' to finish at"));
+    }
+
+    Ok((path, graph))
+}
+
+// Steps from AAA to ZZZ, following the path on repeat
+// Each state is a node and a position in the path, so once a state repeats the walk is going round in circles
+// Returns None if ZZZ is never reached
+fn traverse_graph(path: &Path, graph: &Graph) -> Option<usize> {
+    let step = |&(node, index): &(&str, usize)| {
+        let node = &graph[node];
+        let next = match path[index] {
+            Direction::L => &node.left,
+            Direction::R => &node.right,
+        };
+        [(next.as_str(), (index + 1) % path.len())]
+    };
+
+    search::bfs([("AAA", 0)], step, |&(node, _)| graph[node].id == "ZZZ").cost()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_unknown_nodes() {
+        let input = "RL
+
+AAA = (BBB, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(2, 7, "unknown node 'BBB'")));
+    }
+
+    #[test]
+    fn it_rejects_graphs_without_zzz() {
+        assert_eq!(part1("R\n\nAAA = (AAA, AAA)"), Err(ParseError::new(0, 0, "no node 'ZZZ' to finish at")));
+    }
+
+    #[test]
+    fn it_reports_an_unreachable_zzz() {
+        let input = "RL
+
+AAA = (BBB, BBB)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(part1(input), Err(ParseError::new(0, 0, "ZZZ is unreachable from AAA")));
+    }
+}
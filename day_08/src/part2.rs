@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use aoc_core::math::least_common_multiple;
+use aoc_core::{search, solution, Answer, ParseError};
+use regex::Regex;
+
+enum Direction {
+    L,
+    R,
+}
+
+type Path = Vec<Direction>;
+
+type Graph = HashMap<String, Node>;
+
+struct Node {
+    id: String,
+    left: String,
+    right: String,
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    parse_input(input).and_then(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::try_timed(input, parse_input, solve)
+}
+
+fn solve((path, graph): (Path, Graph)) -> Result<usize, ParseError> {
+    simultaneously_traverse_graph(&path, &graph)
+}
+
+fn parse_input(input: &str) -> Result<(Path, Graph), ParseError> {
+    let path: Path = input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .enumerate()
+        .map(|(column, char)| match char {
+            'L' => Ok(Direction::L),
+            'R' => Ok(Direction::R),
+            _ => Err(ParseError::new(0, column, format!("invalid direction '{}'", char))),
+        })
+        .collect::<Result<Path, ParseError>>()?;
+
+    if path.is_empty() {
+        return Err(ParseError::new(0, 0, "expected a list of directions"));
+    }
+
+    let re = Regex::new(r"^([A-Z0-9]{3}) = \(([A-Z0-9]{3}), ([A-Z0-9]{3})\)$").unwrap();
+    let mut graph: Graph = Graph::new();
+    for (line_index, line) in input.lines().enumerate().skip(2) {
+        let captures = re
+            .captures(line)
+            .ok_or(ParseError::new(line_index, 0, "expected a node in the format 'AAA = (BBB, CCC)'"))?;
+        let id = captures[1].to_string();
+        let left = captures[2].to_string();
+        let right = captures[3].to_string();
+
+        graph.insert(id.clone(), Node { id, left, right });
+    }
+
+    // Check every node leads somewhere, so the graph can be traversed without failing
+    for (line_index, line) in input.lines().enumerate().skip(2) {
+        let captures = re.captures(line).unwrap();
+        for neighbour in [captures.get(2).unwrap(), captures.get(3).unwrap()] {
+            if !graph.contains_key(neighbour.as_str()) {
+                return Err(ParseError::new(line_index, neighbour.start(), format!("unknown node '{}'", neighbour.as_str())));
+            }
+        }
+    }
+
+    if !graph.keys().any(|key| key.ends_with('A')) {
+        return Err(ParseError::new(0, 0, "no nodes ending in 'A' to start from"));
+    }
+
+    Ok((path, graph))
+}
+
+// Steps from origin to the first node ending in Z, following the path on repeat
+// Each state is a node and a position in the path, so once a state repeats the walk is going round in circles
+// Returns None if no node ending in Z is reached
+fn traverse_graph(origin: &str, path: &Path, graph: &Graph) -> Option<usize> {
+    let step = |&(node, index): &(&str, usize)| {
+        let node = &graph[node];
+        let next = match path[index] {
+            Direction::L => &node.left,
+            Direction::R => &node.right,
+        };
+        [(next.as_str(), (index + 1) % path.len())]
+    };
+
+    search::bfs([(origin, 0)], step, |&(node, _)| graph[node].id.ends_with('Z')).cost()
+}
+
+fn simultaneously_traverse_graph(path: &Path, graph: &Graph) -> Result<usize, ParseError> {
+    let step_counts: Vec<usize> = graph
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|origin| {
+            traverse_graph(origin, path, graph)
+                .ok_or(ParseError::new(0, 0, format!("no node ending in 'Z' is reachable from {}", origin)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(least_common_multiple(&step_counts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_graphs_without_a_start() {
+        assert_eq!(part2("R\n\nBBB = (BBB, BBB)"), Err(ParseError::new(0, 0, "no nodes ending in 'A' to start from")));
+    }
+
+    #[test]
+    fn it_reports_starts_which_never_reach_an_end() {
+        let input = "R
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11Z)
+22A = (22A, 22A)";
+        assert_eq!(part2(input), Err(ParseError::new(0, 0, "no node ending in 'Z' is reachable from 22A")));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+type Report = Vec<Vec<i32>>;
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve(report: Report) -> i32 {
+    let next_values = analyse_report(report);
+    next_values.iter().sum()
+}
+
+fn parse_input(input: &str) -> Result<Report, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            if line.trim().is_empty() { return Err(ParseError::new(line_index, 0, "expected a history of numbers")); };
+
+            line
+                .split_whitespace()
+                .map(|str| parse::number(line_index, line, str))
+                .collect()
+        })
+        .collect()
+}
+
+fn analyse_report(report: Report) -> Vec<i32> {
+    report.iter().map(|seq| calculate_next(seq)).collect()
+}
+
+fn calculate_next(seq: &[i32]) -> i32 {
+    let mut progressions: Vec<Vec<i32>> = Vec::new();
+    let mut current_progression = calculate_progression(seq);
+    progressions.push(current_progression.clone());
+    let mut is_final_progression = false;
+
+    // Travel "down" progressions
+    while !is_final_progression {
+        current_progression = calculate_progression(&current_progression);
+        progressions.push(current_progression.clone());
+        is_final_progression = check_is_final_progression(&current_progression);
+    }
+
+    // Track last number in progressions, short histories run out of differences so assume 0
+    let last: Vec<i32> = progressions.iter().map(|p| p.last().copied().unwrap_or(0)).rev().collect();
+
+    // Calculate next number in progressions
+    let mut next: Vec<i32> = vec![0];
+
+    // Iterate "back up" progressions
+    for i in 0..last.len() - 1 {
+        next.push(next[i] + last[i + 1]);
+    }
+    let next_in_seq = seq.last().unwrap() + next.last().unwrap();
+    
+    next_in_seq
+}
+
+fn calculate_progression(seq: &[i32]) -> Vec<i32> {
+    seq
+        .windows(2)
+        .map(|diff| diff[1] - diff[0])
+        .collect()
+}
+
+// Differences are all 0 once they stop changing, or once there are none left
+fn check_is_final_progression(progression: &[i32]) -> bool {
+    progression.iter().all(|&diff| diff == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_extrapolates_short_histories() {
+        assert_eq!(part1("0 3 6"), Ok(9));
+        assert_eq!(part1("4 6\n5"), Ok(13));
+        assert_eq!(part1("0 0 1"), Ok(3));
+    }
+
+    #[test]
+    fn it_rejects_empty_histories() {
+        assert_eq!(part1("1 2\n\n3"), Err(ParseError::new(1, 0, "expected a history of numbers")));
+    }
+}
@@ -0,0 +1,87 @@
+use aoc_core::{parse, solution, Answer, ParseError};
+
+type Report = Vec<Vec<i32>>;
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn solve(report: Report) -> i32 {
+    let prev_values = analyse_report(report);
+    prev_values.iter().sum()
+}
+
+fn parse_input(input: &str) -> Result<Report, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            if line.trim().is_empty() { return Err(ParseError::new(line_index, 0, "expected a history of numbers")); };
+
+            line
+                .split_whitespace()
+                .map(|str| parse::number(line_index, line, str))
+                .collect()
+        })
+        .collect()
+}
+
+fn analyse_report(report: Report) -> Vec<i32> {
+    report.iter().map(|seq| calculate_prev(seq)).collect()
+}
+
+fn calculate_prev(seq: &[i32]) -> i32 {
+    let mut progressions: Vec<Vec<i32>> = Vec::new();
+    let mut current_progression = calculate_progression(seq);
+    progressions.push(current_progression.clone());
+    let mut is_final_progression = false;
+
+    // Travel "down" progressions
+    while !is_final_progression {
+        current_progression = calculate_progression(&current_progression);
+        progressions.push(current_progression.clone());
+        is_final_progression = check_is_final_progression(&current_progression);
+    }
+
+    // Track first number in progressions, short histories run out of differences so assume 0
+    let first: Vec<i32> = progressions.iter().map(|p| p.first().copied().unwrap_or(0)).rev().collect();
+
+    // Calculate prev number in progressions
+    let mut prev: Vec<i32> = vec![0];
+
+    // Iterate "back up" progressions
+    for i in 0..first.len() - 1 {
+        prev.push(first[i + 1] - prev[i]);
+    }
+    let prev_in_seq = seq.first().unwrap() - prev.last().unwrap();
+    
+    prev_in_seq
+}
+
+fn calculate_progression(seq: &[i32]) -> Vec<i32> {
+    seq
+        .windows(2)
+        .map(|diff| diff[1] - diff[0])
+        .collect()
+}
+
+// Differences are all 0 once they stop changing, or once there are none left
+fn check_is_final_progression(progression: &[i32]) -> bool {
+    progression.iter().all(|&diff| diff == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_extrapolates_short_histories_backwards() {
+        assert_eq!(part2("0 3 6"), Ok(-3));
+        assert_eq!(part2("4 6\n5"), Ok(7));
+        assert_eq!(part2("1 2\n\n3"), Err(ParseError::new(1, 0, "expected a history of numbers")));
+    }
+}
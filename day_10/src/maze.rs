@@ -0,0 +1,193 @@
+use aoc_core::{Coord, Direction, Grid, ParseError};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tile {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+pub struct Maze {
+    grid: Grid<Tile>,
+    pub start: Coord,
+    // Tiles around the loop in order, beginning at the start
+    pub path: Vec<Coord>,
+}
+
+impl Maze {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::parse(input, Maze::char_to_maze_tile)?;
+        let start = grid
+            .position(|&tile| tile == Tile::Start)
+            .ok_or(ParseError::new(0, 0, "no start position 'S' found"))?;
+
+        grid[start] = Maze::pipe_under_start(&grid, start)?;
+
+        let mut maze = Maze { grid, start, path: Vec::new() };
+        let (x, y) = start;
+        maze.path = maze.walk_loop().ok_or(ParseError::new(y, x, "the pipes from the start don't lead back to it"))?;
+
+        Ok(maze)
+    }
+
+    // The start is on the loop, so its pipe joins the only two neighbours with pipes leading back to it
+    fn pipe_under_start(grid: &Grid<Tile>, start: Coord) -> Result<Tile, ParseError> {
+        let sides: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|&direction| {
+                grid.step(start, direction).is_some_and(|next| Maze::connects(grid[next], direction.opposite()))
+            })
+            .collect();
+
+        let (x, y) = start;
+        match sides[..] {
+            [first, second] => Ok(Maze::pipe_between(first, second)),
+            _ => Err(ParseError::new(y, x, format!("expected 2 pipes to connect to the start, found {}", sides.len()))),
+        }
+    }
+
+    // Sides are in the order of Direction::ALL, so each pipe has a single pair
+    fn pipe_between(first: Direction, second: Direction) -> Tile {
+        match (first, second) {
+            (Direction::Up, Direction::Down) => Tile::NorthSouth,
+            (Direction::Left, Direction::Right) => Tile::EastWest,
+            (Direction::Up, Direction::Right) => Tile::NorthEast,
+            (Direction::Up, Direction::Left) => Tile::NorthWest,
+            (Direction::Down, Direction::Left) => Tile::SouthWest,
+            (Direction::Down, Direction::Right) => Tile::SouthEast,
+            _ => unreachable!(),
+        }
+    }
+
+    fn char_to_maze_tile(char: char) -> Option<Tile> {
+        let tile = match char {
+            '|' => Tile::NorthSouth,
+            '-' => Tile::EastWest,
+            'L' => Tile::NorthEast,
+            'J' => Tile::NorthWest,
+            '7' => Tile::SouthWest,
+            'F' => Tile::SouthEast,
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            _ => return None,
+        };
+        Some(tile)
+    }
+
+    // Whether the tile has a pipe leading out of the given side
+    // The start is replaced by the pipe under it when parsing, so never connects
+    fn connects(tile: Tile, side: Direction) -> bool {
+        matches!(
+            (tile, side),
+            (Tile::NorthSouth, Direction::Up | Direction::Down)
+                | (Tile::EastWest, Direction::Left | Direction::Right)
+                | (Tile::NorthEast, Direction::Up | Direction::Right)
+                | (Tile::NorthWest, Direction::Up | Direction::Left)
+                | (Tile::SouthWest, Direction::Down | Direction::Left)
+                | (Tile::SouthEast, Direction::Down | Direction::Right)
+        )
+    }
+
+    // Neighbouring tiles joined to this one by a pipe
+    fn connected(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let tile = self.grid[coord];
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| Maze::connects(tile, direction))
+            .filter_map(move |direction| {
+                let next = self.grid.step(coord, direction)?;
+                Maze::connects(self.grid[next], direction.opposite()).then_some(next)
+            })
+    }
+
+    // Follow the pipes from the start, returns None if they don't lead back to it
+    fn walk_loop(&self) -> Option<Vec<Coord>> {
+        let mut path = vec![self.start];
+        let mut previous = self.start;
+        let mut current = self.connected(self.start).next()?;
+
+        while current != self.start {
+            path.push(current);
+            let next = self.connected(current).find(|&next| next != previous)?;
+            previous = current;
+            current = next;
+        }
+
+        Some(path)
+    }
+}
+
+// Tiles inside the loop, from Pick's theorem: area = interior + boundary / 2 - 1
+// The area is found with the shoelace formula, treating each tile on the path as a vertex
+pub fn enclosed_tiles(path: &[Coord]) -> usize {
+    let vertices: Vec<(i64, i64)> = path.iter().map(|&(x, y)| (x as i64, y as i64)).collect();
+    let twice_area: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1)
+        .sum();
+
+    (twice_area.unsigned_abs() as usize + 2).saturating_sub(path.len()) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enclosed(input: &str) -> usize {
+        enclosed_tiles(&Maze::new(input).unwrap().path)
+    }
+
+    #[test]
+    fn it_finds_the_pipe_under_the_start() {
+        let start_tile = |input: &str| {
+            let maze = Maze::new(input).unwrap();
+            maze.grid[maze.start]
+        };
+
+        assert_eq!(start_tile(include_str!("../examples/01.txt")), Tile::SouthEast);
+        assert_eq!(start_tile(include_str!("../examples/04.txt")), Tile::SouthEast);
+        assert_eq!(start_tile(include_str!("../examples/05.txt")), Tile::SouthWest);
+        assert_eq!(start_tile("FS7\nL-J"), Tile::EastWest);
+    }
+
+    #[test]
+    fn it_rejects_starts_without_two_connecting_pipes() {
+        let error = |input: &str| Maze::new(input).err();
+
+        assert_eq!(error("...\n.S.\n..."), Some(ParseError::new(1, 1, "expected 2 pipes to connect to the start, found 0")));
+        assert_eq!(error(".|.\n-S-\n..."), Some(ParseError::new(1, 1, "expected 2 pipes to connect to the start, found 3")));
+        assert_eq!(error("S-"), Some(ParseError::new(0, 0, "expected 2 pipes to connect to the start, found 1")));
+    }
+
+    #[test]
+    fn it_walks_the_loop() {
+        let maze = Maze::new(include_str!("../examples/01.txt")).unwrap();
+        let path = maze.path;
+
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], (1, 1));
+        assert!(path.windows(2).all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+    }
+
+    #[test]
+    fn it_counts_enclosed_tiles() {
+        assert_eq!(enclosed(include_str!("../examples/01.txt")), 1);
+        assert_eq!(enclosed(include_str!("../examples/03.txt")), 4);
+        assert_eq!(enclosed(include_str!("../examples/04.txt")), 8);
+        assert_eq!(enclosed(include_str!("../examples/05.txt")), 10);
+    }
+
+    #[test]
+    fn it_rejects_pipes_which_dont_loop_back_to_the_start() {
+        assert_eq!(
+            Maze::new("S-7\n|.|\nL-.").err(),
+            Some(ParseError::new(0, 0, "the pipes from the start don't lead back to it"))
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::maze::Maze;
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Maze::new(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, Maze::new, solve)
+}
+
+// Following the loop both ways from the start, the farthest point is where they meet halfway round
+fn solve(maze: Maze) -> usize {
+    maze.path.len() / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_invalid_tiles() {
+        let input = ".....
+.S-7.
+.|X|.
+.L-J.
+.....";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(2, 2, "invalid tile 'X'")));
+    }
+
+    #[test]
+    fn it_rejects_broken_loops() {
+        let result = part1("S-7\n|.|\nL-.");
+        assert_eq!(result, Err(ParseError::new(0, 0, "the pipes from the start don't lead back to it")));
+    }
+}
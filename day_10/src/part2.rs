@@ -0,0 +1,15 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::maze::{self, Maze};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Maze::new(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, Maze::new, solve)
+}
+
+fn solve(maze: Maze) -> usize {
+    maze::enclosed_tiles(&maze.path)
+}
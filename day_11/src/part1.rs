@@ -0,0 +1,124 @@
+use aoc_core::{solution, Answer, Grid, ParseError};
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+enum Cosmic {
+    EmptySpace,
+    Galaxy,
+}
+
+type Coord = (i32, i32);
+
+type Matrix = Grid<Cosmic>;
+
+type Network = Vec<Vec<i32>>;
+
+struct Universe {
+    galaxies: Vec<Coord>,
+}
+
+impl Universe {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let matrix = Grid::parse(input, Universe::map_char_to_cosmic)?;
+        let galaxies = Universe::find_galaxies(&matrix);
+        let galaxies = Universe::cosmic_expansion(&matrix, galaxies);
+
+        Ok(Universe { galaxies })
+    }
+
+    fn map_char_to_cosmic(char: char) -> Option<Cosmic> {
+        match char {
+            '#' => Some(Cosmic::Galaxy),
+            '.' => Some(Cosmic::EmptySpace),
+            _ => None,
+        }
+    }
+
+    // Each empty row and column doubles in size, pushing galaxies beyond it further away
+    fn cosmic_expansion(matrix: &Matrix, galaxies: Vec<Coord>) -> Vec<Coord> {
+        // Scan for empty rows
+        let empty_rows: Vec<i32> = matrix
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|&cosmic| cosmic == Cosmic::EmptySpace))
+            .map(|(row_index, _)| row_index as i32)
+            .collect();
+
+        // Scan for empty columns
+        let empty_cols: Vec<i32> = matrix
+            .columns()
+            .enumerate()
+            .filter_map(|(col_index, mut col)| col
+                .all(|&cosmic| cosmic == Cosmic::EmptySpace)
+                .then_some(col_index as i32))
+            .collect();
+
+        galaxies
+            .into_iter()
+            .map(|(row_index, col_index)| {
+                let expanded_rows = empty_rows.iter().filter(|&&row| row < row_index).count() as i32;
+                let expanded_cols = empty_cols.iter().filter(|&&col| col < col_index).count() as i32;
+                (row_index + expanded_rows, col_index + expanded_cols)
+            })
+            .collect()
+    }
+
+    fn find_galaxies(matrix: &Matrix) -> Vec<Coord> {
+        matrix
+            .iter()
+            .filter(|(_, &cosmic)| cosmic == Cosmic::Galaxy)
+            .map(|((col_index, row_index), _)| (row_index as i32, col_index as i32))
+            .collect()
+    }
+
+    fn build_network(&self) -> Network {
+        let mut network: Network = Vec::new();
+
+        for origin in self.galaxies.iter() {
+            let galaxy_network = self.galaxies
+                .iter()
+                .map(|destination| self.diff_galaxies(origin, destination))
+                .collect();
+            network.push(galaxy_network);
+        }
+
+        network
+    }
+
+    fn diff_galaxies(&self, origin: &Coord, destination: &Coord) -> i32 {
+        // Same galaxy - zero steps away
+        if origin == destination { return 0; };
+
+        let (origin_x, origin_y) = origin;
+        let (destination_x, destination_y) = destination;
+
+        // Don't match previous galaxies
+        if origin_x > destination_x { return -1; };
+        if origin_x == destination_x && origin_y > destination_y { return -1; };
+
+        let diff_x = destination_x - origin_x;
+        let diff_y = destination_y - origin_y;
+
+        i32::abs(diff_x) + i32::abs(diff_y)
+    }
+
+}
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    Universe::new(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, Universe::new, solve)
+}
+
+fn solve(universe: Universe) -> i32 {
+    let network = universe.build_network();
+    let sum_shortest_paths: i32 = network
+        .iter()
+        .flat_map(|galaxy_network| galaxy_network
+            .iter()
+            .filter(|&distance| distance > &0))
+        .sum();
+
+    sum_shortest_paths
+}
@@ -0,0 +1,181 @@
+use aoc_core::{solution, Answer, Grid, ParseError};
+
+const EXPANSION: i64 = 1_000_000;
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+enum Cosmic {
+    EmptySpace,
+    Galaxy,
+}
+
+type Coord = (i64, i64);
+
+type Matrix = Grid<Cosmic>;
+
+type Network = Vec<Vec<i64>>;
+
+struct Universe {
+    expansion_rows: Vec<i64>,
+    expansion_cols: Vec<i64>,
+    expansion: i64,
+    galaxies: Vec<Coord>,
+}
+
+impl Universe {
+    fn new(input: &str, expansion: i64) -> Result<Self, ParseError> {
+        let matrix = Grid::parse(input, Universe::map_char_to_cosmic)?;
+        let (expansion_rows, expansion_cols) = Universe::cosmic_expansion(&matrix);
+        let galaxies = Universe::find_galaxies(&matrix);
+
+        Ok(Universe { galaxies, expansion_rows, expansion_cols, expansion })
+    }
+
+    fn map_char_to_cosmic(char: char) -> Option<Cosmic> {
+        match char {
+            '#' => Some(Cosmic::Galaxy),
+            '.' => Some(Cosmic::EmptySpace),
+            _ => None,
+        }
+    }
+
+    fn cosmic_expansion(matrix: &Matrix) -> (Vec<i64>, Vec<i64>) {
+        // Scan for empty rows
+        let empty_rows: Vec<i64> = matrix
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|&cosmic| cosmic == Cosmic::EmptySpace))
+            .map(|(row_index, _)| row_index as i64)
+            .collect();
+
+        // Scan for empty columns
+        let empty_cols: Vec<i64> = matrix
+            .columns()
+            .enumerate()
+            .filter_map(|(col_index, mut col)| col
+                .all(|&cosmic| cosmic == Cosmic::EmptySpace)
+                .then_some(col_index as i64))
+            .collect();
+
+        (empty_rows, empty_cols)
+    }
+
+    fn find_galaxies(matrix: &Matrix) -> Vec<Coord> {
+        matrix
+            .iter()
+            .filter(|(_, &cosmic)| cosmic == Cosmic::Galaxy)
+            .map(|((col_index, row_index), _)| (col_index as i64, row_index as i64))
+            .collect()
+    }
+
+    fn build_network(&self) -> Network {
+        let mut network: Network = Vec::new();
+
+        for origin in self.galaxies.iter() {
+            let galaxy_network = self.galaxies
+                .iter()
+                .map(|destination| self.diff_galaxies(origin, destination))
+                .collect();
+            network.push(galaxy_network);
+        }
+
+        network
+    }
+
+    fn diff_galaxies(&self, origin: &Coord, destination: &Coord) -> i64 {
+        // Same galaxy - zero steps away
+        if origin == destination { return 0; };
+
+        let (origin_x, origin_y) = origin;
+        let (destination_x, destination_y) = destination;
+
+        // Don't match previously visited galaxies
+        if origin_y > destination_y { return -1; }; // Above
+        if origin_y == destination_y && destination_x < origin_x  { return -1; }; // Same row, to the left
+
+        let mut diff_x = i64::abs(destination_x - origin_x);
+        let mut diff_y = i64::abs(destination_y - origin_y);
+
+        // Add cosmic expansion to diffs
+        let mut range_x = *origin_x..*destination_x;
+        let mut range_y = *origin_y..*destination_y;
+
+        // Use positive ranges
+        if range_x.start > range_x.end { range_x = range_x.end..range_x.start; };
+        if range_y.start > range_y.end { range_y = range_y.end..range_y.start; };
+
+        for step in range_y {
+          if self.expansion_rows.contains(&step) { 
+            diff_x += self.expansion - 1;
+          };
+        };
+
+        for step in range_x {
+          if self.expansion_cols.contains(&step) {
+            diff_y += self.expansion - 1;
+          };
+        };
+
+        diff_x + diff_y
+    }
+
+}
+
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    sum_shortest_paths(input, EXPANSION)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, |input| Universe::new(input, EXPANSION), solve)
+}
+
+fn sum_shortest_paths(input: &str, expansion: i64) -> Result<i64, ParseError> {
+    Universe::new(input, expansion).map(solve)
+}
+
+fn solve(universe: Universe) -> i64 {
+    let network = universe.build_network();
+    let sum_shortest_paths: i64 = network
+        .iter()
+        .flat_map(|galaxy_network| galaxy_network
+            .iter()
+            .filter(|&distance| distance > &0))
+        .sum();
+
+    sum_shortest_paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_solves_example_with_expansion_10() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let result = sum_shortest_paths(input, 10).unwrap();
+        assert_eq!(result, 1030);
+    }
+    #[test]
+    fn it_solves_example_with_expansion_100() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let result = sum_shortest_paths(input, 100).unwrap();
+        assert_eq!(result, 8410);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_12::springs::{self, Unfold};
+
+// List the valid arrangements of each row, alongside the groups of damaged springs they make
+#[derive(Parser)]
+#[command(about = "List the valid arrangements of each row of springs")]
+struct Cli {
+    /// Path to the puzzle input, read from AOC_INPUT or stdin if not provided
+    input: Option<PathBuf>,
+    /// Only list arrangements for this row, numbered from 1
+    #[arg(long)]
+    row: Option<usize>,
+    /// Most arrangements to list for each row
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
+    /// Number of copies of each row to join together
+    #[arg(long, default_value_t = 1)]
+    folds: usize,
+    /// Spring joining each copy of a row
+    #[arg(long, default_value_t = '?', value_parser = springs::parse_separator)]
+    separator: char,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input = aoc_core::input::load(cli.input.as_deref()).expect("Unable to read input");
+
+    let records = match springs::parse_records(&input, Unfold { factor: cli.folds, separator: cli.separator }) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("{}", err.report(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if cli.row.is_some_and(|row| row == 0 || row > records.len()) {
+        eprintln!("Expected a row from 1 to {}", records.len());
+        return ExitCode::FAILURE;
+    }
+
+    for (index, record) in records.iter().enumerate() {
+        let row = index + 1;
+        if cli.row.is_some_and(|only| only != row) { continue; };
+
+        let count = record.count_arrangements();
+        println!("Row {}: {} has {} arrangements", row, record, count);
+        for arrangement in record.arrangements().take(cli.limit) {
+            println!("  {} {}", arrangement, springs::format_groups(&springs::groups_of(&arrangement)));
+        }
+        if count > cli.limit as u64 {
+            println!("  ... and {} more", count - cli.limit as u64);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_12::springs::{self, Unfold};
+
+// Table of arrangement counts for each row, as the row is unfolded more times
+#[derive(Parser)]
+#[command(about = "Count arrangements of each row of springs for 1 up to N folds")]
+struct Cli {
+    /// Path to the puzzle input, read from AOC_INPUT or stdin if not provided
+    input: Option<PathBuf>,
+    /// Largest number of copies of each row to count arrangements for
+    #[arg(long, default_value_t = 5)]
+    folds: usize,
+    /// Spring joining each copy of a row
+    #[arg(long, default_value_t = '?', value_parser = springs::parse_separator)]
+    separator: char,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input = aoc_core::input::load(cli.input.as_deref()).expect("Unable to read input");
+
+    let records = match springs::parse_records(&input, Unfold::NONE) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("{}", err.report(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let width = input.lines().map(str::len).max().unwrap_or(0).max(6);
+    let folds: Vec<String> = (1..=cli.folds).map(|fold| format!("{:>16}", fold)).collect();
+    println!("{:<width$}{}", "Record", folds.join(""));
+
+    let mut totals = vec![0; cli.folds];
+    for (line, record) in input.lines().zip(records) {
+        let counts = record.counts_per_fold(cli.folds, cli.separator);
+        let columns: Vec<String> = counts.iter().map(|count| format!("{:>16}", count)).collect();
+        println!("{:<width$}{}", line, columns.join(""));
+
+        for (total, count) in totals.iter_mut().zip(counts) {
+            *total += count;
+        }
+    }
+
+    let columns: Vec<String> = totals.iter().map(|total| format!("{:>16}", total)).collect();
+    println!("{:<width$}{}", "Total", columns.join(""));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,66 @@
+use rayon::prelude::*;
+use aoc_core::{solution, Answer, ParseError};
+use crate::springs::{self, Record, Unfold};
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    springs::parse_records(input, Unfold::NONE)
+}
+
+fn solve(records: Vec<Record>) -> u64 {
+    records.par_iter().map(Record::count_arrangements).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_solves_test_case_1() {
+        let input = "???.### 1,1,3";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn it_solves_test_case_2() {
+        let input = ".??..??...?##. 1,1,3";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn it_solves_test_case_3() {
+        let input = "?#?#?#?#?#?#?#? 1,3,1,6";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn it_solves_test_case_4() {
+        let input = "????.#...#... 4,1,1";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn it_solves_test_case_5() {
+        let input = "????.######..#####. 1,6,5";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn it_solves_test_case_6() {
+        let input = "?###???????? 3,2,1";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 10);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fmt;
+use aoc_core::{parse, ParseError};
+
+// A row of springs, which are operational '.', damaged '#' or unknown '?'
+// Groups are the sizes of each contiguous run of damaged springs, in order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<char>,
+    pub groups: Vec<usize>,
+}
+
+// How many copies of each record to join together, and the spring to join them with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unfold {
+    pub factor: usize,
+    pub separator: char,
+}
+
+impl Unfold {
+    pub const NONE: Unfold = Unfold { factor: 1, separator: '?' };
+    pub const PUZZLE: Unfold = Unfold { factor: 5, separator: '?' };
+}
+
+impl Record {
+    // Repeat the springs and groups, with the separator between each copy of the springs
+    pub fn unfold(&self, Unfold { factor, separator }: Unfold) -> Record {
+        let springs = vec![self.springs.clone(); factor].join(&separator);
+        let groups = self.groups.repeat(factor);
+
+        Record { springs, groups }
+    }
+
+    // Arrangements for each number of folds, from 1 up to max_folds
+    pub fn counts_per_fold(&self, max_folds: usize, separator: char) -> Vec<u64> {
+        (1..=max_folds)
+            .map(|factor| self.unfold(Unfold { factor, separator }).count_arrangements())
+            .collect()
+    }
+
+    // Number of ways to replace the unknown springs so that the damaged springs match the groups
+    pub fn count_arrangements(&self) -> u64 {
+        self.count_from((0, 0, 0), &mut HashMap::new())
+    }
+
+    // Every way to replace the unknown springs, found lazily one at a time
+    pub fn arrangements(&self) -> Arrangements<'_> {
+        let start = (0, 0, 0);
+        let mut memo = HashMap::new();
+        let stack = match self.count_from(start, &mut memo) {
+            0 => vec![],
+            _ => vec![(start, String::new())],
+        };
+
+        Arrangements { record: self, memo, stack }
+    }
+
+    // Arrangements of the remaining springs, from the given progress through the record
+    fn count_from(&self, progress: Progress, memo: &mut HashMap<Progress, u64>) -> u64 {
+        let (position, group_index, run) = progress;
+        if position == self.springs.len() {
+            let all_matched = group_index == self.groups.len() && run == 0;
+            let last_matched = group_index + 1 == self.groups.len() && run == self.groups[group_index];
+            return u64::from(all_matched || last_matched);
+        }
+
+        if let Some(&count) = memo.get(&progress) { return count; };
+
+        let count = self
+            .next_steps(progress)
+            .into_iter()
+            .map(|(_, next)| self.count_from(next, memo))
+            .sum();
+
+        memo.insert(progress, count);
+        count
+    }
+
+    // Each spring which can be at the current position, with the progress after it
+    fn next_steps(&self, (position, group_index, run): Progress) -> Vec<(char, Progress)> {
+        let mut steps = Vec::new();
+        let spring = self.springs[position];
+
+        if matches!(spring, '.' | '?') {
+            if run == 0 {
+                steps.push(('.', (position + 1, group_index, 0)));
+            } else if run == self.groups[group_index] {
+                steps.push(('.', (position + 1, group_index + 1, 0)));
+            }
+        }
+
+        if matches!(spring, '#' | '?') && self.groups.get(group_index).is_some_and(|&group| run < group) {
+            steps.push(('#', (position + 1, group_index, run + 1)));
+        }
+
+        steps
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let springs: String = self.springs.iter().collect();
+        write!(f, "{} {}", springs, format_groups(&self.groups))
+    }
+}
+
+// Position in the springs, number of groups matched, and damaged springs so far in the current group
+type Progress = (usize, usize, usize);
+
+// Depth first search of the arrangements, only following springs which lead to at least one arrangement
+pub struct Arrangements<'a> {
+    record: &'a Record,
+    memo: HashMap<Progress, u64>,
+    stack: Vec<(Progress, String)>,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while let Some((progress, springs)) = self.stack.pop() {
+            if progress.0 == self.record.springs.len() { return Some(springs); };
+
+            // Pushed in reverse, so that operational springs are tried first
+            for (spring, next) in self.record.next_steps(progress).into_iter().rev() {
+                if self.record.count_from(next, &mut self.memo) > 0 {
+                    self.stack.push((next, format!("{}{}", springs, spring)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// Sizes of each contiguous run of damaged springs
+pub fn groups_of(springs: &str) -> Vec<usize> {
+    springs.split('.').filter(|run| !run.is_empty()).map(str::len).collect()
+}
+
+pub fn format_groups(groups: &[usize]) -> String {
+    groups.iter().map(usize::to_string).collect::<Vec<_>>().join(",")
+}
+
+// Separators must be springs, for command line options
+pub fn parse_separator(value: &str) -> Result<char, String> {
+    match value {
+        "?" | "." | "#" => Ok(value.chars().next().unwrap()),
+        _ => Err(String::from("expected one of '?', '.' or '#'")),
+    }
+}
+
+pub fn parse_records(input: &str, unfold: Unfold) -> Result<Vec<Record>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_record(line).map(|record| record.unfold(unfold)).map_err(|err| ParseError { line: i, ..err }))
+        .collect()
+}
+
+// Parse a single record, errors are reported against line 0
+fn parse_record(input: &str) -> Result<Record, ParseError> {
+    let (springs, groups) = input
+        .split_once(' ')
+        .ok_or(ParseError::new(0, input.len(), "expected springs followed by a pattern"))?;
+
+    if let Some((column, char)) = springs.char_indices().find(|(_, char)| !matches!(char, '.' | '#' | '?')) {
+        return Err(ParseError::new(0, column, format!("invalid spring '{}'", char)));
+    }
+
+    let groups: Vec<usize> = groups
+        .split(',')
+        .map(|str| parse::number(0, input, str))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Record { springs: springs.chars().collect(), groups })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(input: &str) -> Record {
+        parse_record(input).unwrap()
+    }
+
+    #[test]
+    fn it_unfolds_records() {
+        assert_eq!(record(".# 1").unfold(Unfold::PUZZLE), record(".#?.#?.#?.#?.# 1,1,1,1,1"));
+        assert_eq!(record(".# 1").unfold(Unfold { factor: 3, separator: '.' }), record(".#..#..# 1,1,1"));
+        assert_eq!(record("???.### 1,1,3").unfold(Unfold::NONE), record("???.### 1,1,3"));
+    }
+
+    #[test]
+    fn it_counts_arrangements_per_fold() {
+        assert_eq!(record(".??..??...?##. 1,1,3").counts_per_fold(5, '?'), vec![4, 32, 256, 2048, 16384]);
+        assert_eq!(record(".??..??...?##. 1,1,3").counts_per_fold(3, '.'), vec![4, 16, 64]);
+    }
+
+    #[test]
+    fn it_counts_arrangements() {
+        assert_eq!(record("???.### 1,1,3").count_arrangements(), 1);
+        assert_eq!(record("?###???????? 3,2,1").count_arrangements(), 10);
+        assert_eq!(record("?###???????? 3,2,1").unfold(Unfold::PUZZLE).count_arrangements(), 506250);
+        assert_eq!(record("### 2").count_arrangements(), 0);
+        assert_eq!(record("... 1").count_arrangements(), 0);
+    }
+
+    #[test]
+    fn it_lists_arrangements() {
+        assert_eq!(record("???.### 1,1,3").arrangements().collect::<Vec<_>>(), vec!["#.#.###"]);
+        assert_eq!(
+            record("????.#...#... 4,1,1").unfold(Unfold { factor: 2, separator: '?' }).arrangements().collect::<Vec<_>>(),
+            vec!["####.#...#....####.#...#...", "####.#...#...####..#...#..."]
+        );
+        assert_eq!(record("### 2").arrangements().next(), None);
+    }
+
+    #[test]
+    fn it_lists_each_arrangement_once() {
+        let record = record("?###???????? 3,2,1");
+        let arrangements: Vec<String> = record.arrangements().collect();
+        let unique: std::collections::HashSet<&String> = arrangements.iter().collect();
+
+        assert_eq!(arrangements.len(), 10);
+        assert_eq!(unique.len(), 10);
+        assert!(arrangements.iter().all(|arrangement| groups_of(arrangement) == record.groups));
+        assert_eq!(record.to_string(), "?###???????? 3,2,1");
+    }
+
+    #[test]
+    fn it_lists_arrangements_lazily() {
+        let record = record("?###???????? 3,2,1").unfold(Unfold { factor: 10, separator: '?' });
+        assert_eq!(record.arrangements().take(3).count(), 3);
+    }
+
+    #[test]
+    fn it_rejects_invalid_springs() {
+        assert_eq!(parse_records("???.### 1,1,3\n??x 1", Unfold::NONE), Err(ParseError::new(1, 2, "invalid spring 'x'")));
+        assert_eq!(parse_records("?é# 1", Unfold::NONE), Err(ParseError::new(0, 1, "invalid spring 'é'")));
+    }
+}
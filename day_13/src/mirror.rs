@@ -0,0 +1,153 @@
+use aoc_core::{Grid, ParseError};
+
+// A pattern of ash '.' and rocks '#', read as both its rows and its columns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    pub rows: Lines,
+    pub columns: Lines,
+}
+
+// Lines packed into bits, set for rocks, so mirrored lines can be compared with XOR and popcount
+// Each line takes as many words as it needs, so lines longer than 64 cells take more than one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lines {
+    words: usize,
+    bits: Vec<u64>,
+}
+
+impl Lines {
+    fn pack<'a>(length: usize, lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Lines {
+        let words = length.div_ceil(64).max(1);
+        let mut bits = Vec::new();
+
+        for line in lines {
+            let mut packed = vec![0; words];
+            for (i, cell) in line.enumerate() {
+                if *cell == '#' { packed[i / 64] |= 1 << (i % 64); };
+            }
+            bits.extend(packed);
+        }
+
+        Lines { words, bits }
+    }
+
+    fn count(&self) -> usize {
+        self.bits.len() / self.words
+    }
+
+    fn line(&self, index: usize) -> &[u64] {
+        &self.bits[index * self.words..(index + 1) * self.words]
+    }
+}
+
+impl Pattern {
+    // 100 for each row above a horizontal reflection, plus each column left of a vertical one
+    pub fn summarise(&self, smudges: usize) -> usize {
+        let rows: usize = reflections(&self.rows, smudges).iter().sum();
+        let columns: usize = reflections(&self.columns, smudges).iter().sum();
+
+        rows * 100 + columns
+    }
+}
+
+// Axes which reflect the lines once exactly `smudges` cells are fixed
+// Each axis is the number of lines before it, so 0 and lines.len() are never axes
+pub fn reflections(lines: &Lines, smudges: usize) -> Vec<usize> {
+    (1..lines.count())
+        .filter(|&axis| mismatches(lines, axis, smudges) == smudges)
+        .collect()
+}
+
+// Cells which differ between each pair of lines across the axis, stopping once over limit
+fn mismatches(lines: &Lines, axis: usize, limit: usize) -> usize {
+    let mut total = 0;
+
+    for (above, below) in (0..axis).rev().zip(axis..lines.count()) {
+        total += hamming_distance(lines.line(above), lines.line(below));
+        if total > limit { break; };
+    }
+
+    total
+}
+
+fn hamming_distance(a: &[u64], b: &[u64]) -> usize {
+    a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones() as usize).sum()
+}
+
+pub fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = Vec::new();
+    let mut line_offset = 0;
+
+    for pattern in input.split("\n\n") {
+        patterns.push(parse_pattern(line_offset, pattern)?);
+        line_offset += pattern.lines().count() + 1;
+    }
+
+    Ok(patterns)
+}
+
+// Patterns are separated by blank lines, so line_offset is the index of the pattern's first line in the input
+fn parse_pattern(line_offset: usize, input: &str) -> Result<Pattern, ParseError> {
+    let grid = Grid::parse(input, |char| matches!(char, '#' | '.').then_some(char))
+        .map_err(|err| ParseError { line: line_offset + err.line, ..err })?;
+
+    let rows = Lines::pack(grid.width(), grid.rows().map(|row| row.iter()));
+    let columns = Lines::pack(grid.height(), grid.columns());
+
+    Ok(Pattern { rows, columns })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATTERNS: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn it_finds_reflections_with_smudges() {
+        let patterns = parse_patterns(PATTERNS).unwrap();
+
+        assert_eq!(reflections(&patterns[0].columns, 0), vec![5]);
+        assert_eq!(reflections(&patterns[0].rows, 0), vec![]);
+        assert_eq!(reflections(&patterns[0].rows, 1), vec![3]);
+        assert_eq!(reflections(&patterns[1].rows, 0), vec![4]);
+        assert_eq!(reflections(&patterns[1].rows, 1), vec![1]);
+    }
+
+    #[test]
+    fn it_summarises_patterns() {
+        let patterns = parse_patterns(PATTERNS).unwrap();
+        assert_eq!(patterns.iter().map(|pattern| pattern.summarise(0)).sum::<usize>(), 405);
+        assert_eq!(patterns.iter().map(|pattern| pattern.summarise(1)).sum::<usize>(), 400);
+    }
+
+    #[test]
+    fn it_finds_reflections_in_wide_patterns() {
+        // Two rows of 70 cells, differing only after the first 64
+        let top = format!("{}#.....", "#.".repeat(32));
+        let pattern = format!("{}\n{}\n{}", top, top.replace("#.....", "..#..."), top);
+        let patterns = parse_patterns(&pattern).unwrap();
+
+        assert_eq!(reflections(&patterns[0].rows, 0), vec![]);
+        assert_eq!(reflections(&patterns[0].rows, 2), vec![1, 2]);
+    }
+
+    #[test]
+    fn it_reports_the_line_of_invalid_cells() {
+        assert_eq!(parse_patterns("#.\n.#\n\n#.\n.x"), Err(ParseError::new(4, 1, "invalid tile 'x'")));
+    }
+}
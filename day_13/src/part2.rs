@@ -0,0 +1,15 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::mirror::{self, Pattern};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    mirror::parse_patterns(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, mirror::parse_patterns, solve)
+}
+
+fn solve(patterns: Vec<Pattern>) -> usize {
+    patterns.iter().map(|pattern| pattern.summarise(1)).sum()
+}
@@ -0,0 +1,73 @@
+use aoc_core::{Coord, Direction, Grid};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_14::platform::{self, Item, Platform};
+
+// Square platform where each tile is a round rock or square rock with the given chance, out of 100
+// Uses a fixed xorshift sequence, so every run benchmarks the same platforms
+fn synthetic_platform(size: usize, round: u64, square: u64) -> Platform {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next_item = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        match state % 100 {
+            roll if roll < round => Item::RoundRock,
+            roll if roll < round + square => Item::SquareRock,
+            _ => Item::EmptySpace,
+        }
+    };
+
+    Grid::from_rows((0..size).map(|_| (0..size).map(|_| next_item()).collect()).collect())
+}
+
+// The previous tilt, which rolls each rock one space at a time on a copy of the platform
+fn tilt_stepwise(platform: &Platform, direction: Direction) -> Platform {
+    let mut tilted = platform.clone();
+
+    let mut coords: Vec<Coord> = platform.coords().collect();
+    if matches!(direction, Direction::Down | Direction::Right) { coords.reverse(); };
+
+    for coord in coords {
+        if tilted[coord] != Item::RoundRock { continue; };
+
+        let mut current = coord;
+        while let Some(next) = tilted.step(current, direction) {
+            if tilted[next] != Item::EmptySpace { break; };
+            tilted[next] = Item::RoundRock;
+            tilted[current] = Item::EmptySpace;
+            current = next;
+        }
+    }
+
+    tilted
+}
+
+fn bench_tilt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_14/tilt");
+
+    // Similar to the puzzle input, and with few square rocks so round rocks roll further
+    let mixes = [("puzzle", 20, 16), ("sparse", 20, 1)];
+
+    for (mix, round, square) in mixes {
+        for size in [100, 500, 1000] {
+            let platform = synthetic_platform(size, round, square);
+            let parameter = format!("{}/{}", mix, size);
+
+            group.bench_with_input(BenchmarkId::new("stepwise", &parameter), &platform, |b, platform| {
+                b.iter(|| tilt_stepwise(platform, Direction::Up))
+            });
+            group.bench_with_input(BenchmarkId::new("sweep", &parameter), &platform, |b, platform| {
+                b.iter(|| {
+                    let mut tilted = platform.clone();
+                    platform::tilt(&mut tilted, Direction::Up);
+                    tilted
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_tilt);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+use aoc_core::{solution, Answer, Direction, ParseError};
+
+use crate::platform::{self, Platform};
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    platform::parse_platform(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, platform::parse_platform, solve)
+}
+
+fn solve(mut platform: Platform) -> usize {
+    platform::tilt(&mut platform, Direction::Up);
+    platform::total_load(&platform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_invalid_tiles() {
+        let input = "O....#....
+O.OO#..X.#";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(1, 7, "invalid tile 'X'")));
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::platform::{self, Platform};
+
+const CYCLES: usize = 1_000_000_000;
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    platform::parse_platform(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, platform::parse_platform, solve)
+}
+
+fn solve(platform: Platform) -> usize {
+    platform::load_after_cycles(&platform, CYCLES)
+}
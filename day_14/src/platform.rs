@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use aoc_core::{Direction, Grid, ParseError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Item {
+    RoundRock,
+    SquareRock,
+    EmptySpace
+}
+
+pub type Platform = Grid<Item>;
+
+pub fn parse_platform(input: &str) -> Result<Platform, ParseError> {
+    Grid::parse(input, char_to_item)
+}
+
+fn char_to_item(char: char) -> Option<Item> {
+    match char {
+        'O' => Some(Item::RoundRock),
+        '#' => Some(Item::SquareRock),
+        '.' => Some(Item::EmptySpace),
+        _ => None,
+    }
+}
+
+// Tilt the platform so that every round rock rolls as far as it can towards direction
+// Each line is swept once from the edge, moving rocks straight to the next free space
+pub fn tilt(platform: &mut Platform, direction: Direction) {
+    let (width, height) = (platform.width(), platform.height());
+    let (lines, length) = match direction {
+        Direction::Up | Direction::Down => (width, height),
+        Direction::Left | Direction::Right => (height, width),
+    };
+
+    for line in 0..lines {
+        // Position along the line, counting from the edge being tilted towards
+        let coord = |i: usize| match direction {
+            Direction::Up => (line, i),
+            Direction::Down => (line, height - 1 - i),
+            Direction::Left => (i, line),
+            Direction::Right => (width - 1 - i, line),
+        };
+
+        let mut free = 0;
+        for i in 0..length {
+            match platform[coord(i)] {
+                Item::SquareRock => free = i + 1,
+                Item::RoundRock => {
+                    if free != i {
+                        platform[coord(i)] = Item::EmptySpace;
+                        platform[coord(free)] = Item::RoundRock;
+                    }
+                    free += 1;
+                },
+                Item::EmptySpace => (),
+            }
+        }
+    }
+}
+
+// Tilt north, then west, then south, then east
+pub fn spin_cycle(platform: &mut Platform) {
+    for direction in [Direction::Up, Direction::Left, Direction::Down, Direction::Right] {
+        tilt(platform, direction);
+    }
+}
+
+// Load on the north support beams after running the spin cycle the given number of times
+// Once a platform repeats, the loads repeat with it, so the rest of the cycles can be skipped
+pub fn load_after_cycles(platform: &Platform, cycles: usize) -> usize {
+    let mut seen: HashMap<Platform, usize> = HashMap::new();
+    let mut loads = Vec::new();
+    let mut platform = platform.clone();
+
+    for done in 0..cycles {
+        if let Some(&start) = seen.get(&platform) {
+            let period = done - start;
+            return loads[start + (cycles - start) % period];
+        }
+
+        seen.insert(platform.clone(), done);
+        loads.push(total_load(&platform));
+        spin_cycle(&mut platform);
+    }
+
+    total_load(&platform)
+}
+
+// Each round rock adds the number of rows from it to the south edge, including its own
+pub fn total_load(platform: &Platform) -> usize {
+    let weighting = platform.height();
+
+    platform
+        .rows()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let rocks = row.iter().filter(|&&item| item == Item::RoundRock).count();
+            rocks * (weighting - row_index)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tilted(input: &str, direction: Direction) -> Platform {
+        let mut platform = parse_platform(input).unwrap();
+        tilt(&mut platform, direction);
+        platform
+    }
+
+    const PLATFORM: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn it_tilts_in_each_direction() {
+        let platform = ".O.#O.\n......";
+        assert_eq!(tilted(platform, Direction::Left), parse_platform("O..#O.\n......").unwrap());
+        assert_eq!(tilted(platform, Direction::Right), parse_platform("..O#.O\n......").unwrap());
+        assert_eq!(tilted(platform, Direction::Down), parse_platform("...#..\n.O..O.").unwrap());
+        assert_eq!(tilted(platform, Direction::Up), parse_platform(platform).unwrap());
+        assert_eq!(tilted("OO.O#.O\n.......", Direction::Right), parse_platform(".OOO#.O\n.......").unwrap());
+    }
+
+    #[test]
+    fn it_runs_spin_cycles() {
+        let after_one = ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+        let mut platform = parse_platform(PLATFORM).unwrap();
+        spin_cycle(&mut platform);
+        assert_eq!(platform, parse_platform(after_one).unwrap());
+    }
+
+    #[test]
+    fn it_skips_repeated_cycles() {
+        let platform = parse_platform(PLATFORM).unwrap();
+        let mut simulated = platform.clone();
+        (0..20).for_each(|_| spin_cycle(&mut simulated));
+
+        assert_eq!(load_after_cycles(&platform, 20), total_load(&simulated));
+        assert_eq!(load_after_cycles(&platform, 1_000_000_000), 64);
+        assert_eq!(load_after_cycles(&platform, 0), total_load(&platform));
+    }
+}
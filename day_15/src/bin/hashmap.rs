@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_15::hashmap::{self, LensBoxes};
+
+// Run the HASHMAP procedure, showing the lenses in each box
+#[derive(Parser)]
+#[command(about = "Show the lenses in each box after running the initialization sequence")]
+struct Cli {
+    /// Path to the puzzle input, read from AOC_INPUT or stdin if not provided
+    input: Option<PathBuf>,
+    /// Show the boxes after every step, rather than only at the end
+    #[arg(long)]
+    trace: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input = aoc_core::input::load(cli.input.as_deref()).expect("Unable to read input");
+
+    let steps = match hashmap::parse_steps(&input) {
+        Ok(steps) => steps,
+        Err(err) => {
+            eprintln!("{}", err.report(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut boxes = LensBoxes::new();
+    for step in &steps {
+        boxes.apply(step);
+        if cli.trace { println!("After \"{}\":\n{}", step, boxes); };
+    }
+
+    if !cli.trace { println!("{}", boxes); };
+    println!("Focusing power: {}", boxes.focusing_power());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use aoc_core::{parse, ParseError};
+use indexmap::IndexMap;
+
+pub const BOX_COUNT: usize = 256;
+
+// Run the HASH algorithm on a string, giving a value from 0 to 255
+pub fn hash(string: &str) -> usize {
+    string.chars().fold(0, |current_value, char| (current_value + char as usize) * 17 % BOX_COUNT)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    // '-' removes the lens with the step's label
+    Remove,
+    // '=' puts a lens with this focal length in the box, replacing any lens with the same label
+    Insert(u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub label: String,
+    pub operation: Operation,
+}
+
+impl Step {
+    // Box the step's lens goes in, or is removed from
+    pub fn box_index(&self) -> usize {
+        hash(&self.label)
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operation {
+            Operation::Remove => write!(f, "{}-", self.label),
+            Operation::Insert(focal_length) => write!(f, "{}={}", self.label, focal_length),
+        }
+    }
+}
+
+// Lenses in each box by label, in the order they were put in the box
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LensBoxes {
+    boxes: Vec<IndexMap<String, u32>>,
+}
+
+impl LensBoxes {
+    pub fn new() -> Self {
+        LensBoxes { boxes: vec![IndexMap::new(); BOX_COUNT] }
+    }
+
+    pub fn boxes(&self) -> &[IndexMap<String, u32>] {
+        &self.boxes
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        let lightbox = &mut self.boxes[step.box_index()];
+
+        match step.operation {
+            Operation::Remove => { lightbox.shift_remove(&step.label); },
+            Operation::Insert(focal_length) => { lightbox.insert(step.label.clone(), focal_length); },
+        }
+    }
+
+    // Each lens adds its box number, times its slot in the box, times its focal length, all counting from 1
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_index, lightbox)| {
+                lightbox
+                    .values()
+                    .enumerate()
+                    .map(move |(slot, &focal_length)| (box_index + 1) * (slot + 1) * focal_length as usize)
+            })
+            .sum()
+    }
+}
+
+impl Default for LensBoxes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Each box with lenses in it, as in the puzzle description, e.g. "Box 3: [ot 7] [ab 5]"
+impl fmt::Display for LensBoxes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (box_index, lightbox) in self.boxes.iter().enumerate().filter(|(_, lightbox)| !lightbox.is_empty()) {
+            let lenses: Vec<String> = lightbox.iter().map(|(label, focal_length)| format!("[{} {}]", label, focal_length)).collect();
+            writeln!(f, "Box {}: {}", box_index, lenses.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
+
+// The input is a single line, so all errors are reported against line 0
+pub fn parse_steps(input: &str) -> Result<Vec<Step>, ParseError> {
+    input.split(',').map(|step| parse_step(input, step)).collect()
+}
+
+fn parse_step(input: &str, step: &str) -> Result<Step, ParseError> {
+    if let Some(label) = step.strip_suffix('-') {
+        return Ok(Step { label: label.to_string(), operation: Operation::Remove });
+    }
+
+    let (label, focal_length) = step
+        .split_once('=')
+        .ok_or(ParseError::at_token(0, input, step, format!("expected '{}' to end with '-' or '=' and a focal length", step)))?;
+
+    if focal_length.is_empty() {
+        return Err(ParseError::at_token(0, input, focal_length, format!("expected a focal length for '{}'", label)));
+    }
+
+    let focal_length = parse::number(0, input, focal_length)?;
+    Ok(Step { label: label.to_string(), operation: Operation::Insert(focal_length) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEPS: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn it_hashes_strings() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+    }
+
+    #[test]
+    fn it_shows_boxes_after_each_step() {
+        let steps = parse_steps(STEPS).unwrap();
+        let mut boxes = LensBoxes::new();
+
+        boxes.apply(&steps[0]);
+        assert_eq!(boxes.to_string(), "Box 0: [rn 1]\n");
+
+        steps[1..5].iter().for_each(|step| boxes.apply(step));
+        assert_eq!(boxes.to_string(), "Box 0: [rn 1] [cm 2]\n");
+
+        steps[5..].iter().for_each(|step| boxes.apply(step));
+        assert_eq!(boxes.to_string(), "Box 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]\n");
+        assert_eq!(boxes.boxes().len(), BOX_COUNT);
+        assert_eq!(boxes.focusing_power(), 145);
+    }
+
+    #[test]
+    fn it_displays_steps_as_written() {
+        let steps = parse_steps(STEPS).unwrap();
+        let written: Vec<String> = steps.iter().map(Step::to_string).collect();
+        assert_eq!(written.join(","), STEPS);
+    }
+
+    #[test]
+    fn it_rejects_steps_without_a_focal_length() {
+        assert_eq!(parse_steps("rn=1,cm="), Err(ParseError::new(0, 8, "expected a focal length for 'cm'")));
+        assert_eq!(
+            parse_steps("rn=1,cm"),
+            Err(ParseError::new(0, 5, "expected 'cm' to end with '-' or '=' and a focal length"))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::hashmap;
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    parse_input(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, parse_input, solve)
+}
+
+// Any string can be hashed, so this never fails to parse
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.split(",").collect())
+}
+
+fn solve(steps: Vec<&str>) -> usize {
+    steps.iter().map(|step| hashmap::hash(step)).sum()
+}
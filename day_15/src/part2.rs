@@ -0,0 +1,17 @@
+use aoc_core::{solution, Answer, ParseError};
+
+use crate::hashmap::{self, LensBoxes, Step};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    hashmap::parse_steps(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, hashmap::parse_steps, solve)
+}
+
+fn solve(steps: Vec<Step>) -> usize {
+    let mut boxes = LensBoxes::new();
+    steps.iter().for_each(|step| boxes.apply(step));
+    boxes.focusing_power()
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+
+use aoc_core::{Coord, Direction, Grid, ParseError};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tile {
+    EmptySpace,
+    ForwardMirror,
+    BackMirror,
+    UpDownSplitter,
+    LeftRightSplitter,
+}
+
+pub type Contraption = Grid<Tile>;
+
+pub fn parse_contraption(input: &str) -> Result<Contraption, ParseError> {
+    Grid::parse(input, char_to_tile)
+}
+
+fn char_to_tile(char: char) -> Option<Tile> {
+    match char {
+        '.' => Some(Tile::EmptySpace),
+        '/' => Some(Tile::ForwardMirror),
+        '\\' => Some(Tile::BackMirror),
+        '|' => Some(Tile::UpDownSplitter),
+        '-' => Some(Tile::LeftRightSplitter),
+        _ => None,
+    }
+}
+
+// Directions a beam leaves a tile in, having entered it moving in the incoming direction
+fn outgoing(tile: Tile, incoming: Direction) -> &'static [Direction] {
+    match (tile, incoming) {
+        (Tile::EmptySpace, Direction::Up) => &[Direction::Up],
+        (Tile::EmptySpace, Direction::Down) => &[Direction::Down],
+        (Tile::EmptySpace, Direction::Left) => &[Direction::Left],
+        (Tile::EmptySpace, Direction::Right) => &[Direction::Right],
+        // Character = /
+        (Tile::ForwardMirror, Direction::Left) => &[Direction::Down],
+        (Tile::ForwardMirror, Direction::Right) => &[Direction::Up],
+        (Tile::ForwardMirror, Direction::Up) => &[Direction::Right],
+        (Tile::ForwardMirror, Direction::Down) => &[Direction::Left],
+        // Character = \
+        (Tile::BackMirror, Direction::Left) => &[Direction::Up],
+        (Tile::BackMirror, Direction::Right) => &[Direction::Down],
+        (Tile::BackMirror, Direction::Up) => &[Direction::Left],
+        (Tile::BackMirror, Direction::Down) => &[Direction::Right],
+        // Character = |
+        (Tile::UpDownSplitter, Direction::Left | Direction::Right) => &[Direction::Up, Direction::Down],
+        (Tile::UpDownSplitter, Direction::Up) => &[Direction::Up],
+        (Tile::UpDownSplitter, Direction::Down) => &[Direction::Down],
+        // Character = -
+        (Tile::LeftRightSplitter, Direction::Up | Direction::Down) => &[Direction::Left, Direction::Right],
+        (Tile::LeftRightSplitter, Direction::Left) => &[Direction::Left],
+        (Tile::LeftRightSplitter, Direction::Right) => &[Direction::Right],
+    }
+}
+
+// Directions beams were moving in as they entered each tile, one bit per direction
+pub struct Energised {
+    visits: Grid<u8>,
+}
+
+impl Energised {
+    fn bit(direction: Direction) -> u8 {
+        match direction {
+            Direction::Up => 1,
+            Direction::Down => 2,
+            Direction::Left => 4,
+            Direction::Right => 8,
+        }
+    }
+
+    // Record a beam entering coord, returns false if a beam has already entered it in this direction
+    fn visit(&mut self, coord: Coord, direction: Direction) -> bool {
+        let visits = &mut self.visits[coord];
+        let is_new = *visits & Energised::bit(direction) == 0;
+        *visits |= Energised::bit(direction);
+        is_new
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.visits[coord] != 0
+    }
+
+    // Number of different directions beams have crossed coord in, from 0 to 4
+    pub fn directions(&self, coord: Coord) -> u32 {
+        self.visits[coord].count_ones()
+    }
+
+    pub fn count(&self) -> usize {
+        self.visits.iter().filter(|(_, &visits)| visits != 0).count()
+    }
+
+    // Binary PPM image with a pixel per tile, brighter for tiles crossed in more directions
+    pub fn to_ppm(&self) -> Vec<u8> {
+        const COLOURS: [[u8; 3]; 5] = [[24, 24, 36], [120, 60, 20], [190, 110, 30], [240, 170, 50], [255, 240, 160]];
+
+        let mut image = format!("P6\n{} {}\n255\n", self.visits.width(), self.visits.height()).into_bytes();
+        for (coord, _) in self.visits.iter() {
+            image.extend(COLOURS[self.directions(coord) as usize]);
+        }
+
+        image
+    }
+}
+
+// Energised tiles as '#', as in the puzzle description
+impl fmt::Display for Energised {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self
+            .visits
+            .rows()
+            .map(|row| row.iter().map(|&visits| if visits != 0 { '#' } else { '.' }).collect())
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+// Follow a beam entering the start tile, and every beam split from it, until each leaves the contraption or repeats
+pub fn trace(contraption: &Contraption, start: Coord, direction: Direction) -> Energised {
+    let mut energised = Energised { visits: Grid::filled(contraption.width(), contraption.height(), 0) };
+    let mut beams = vec![(start, direction)];
+
+    while let Some((coord, incoming)) = beams.pop() {
+        if !energised.visit(coord, incoming) { continue; };
+
+        for &direction in outgoing(contraption[coord], incoming) {
+            if let Some(next) = contraption.step(coord, direction) {
+                beams.push((next, direction));
+            }
+        }
+    }
+
+    energised
+}
+
+// Every tile on the edge of the contraption, entered by a beam moving away from that edge
+pub fn edge_starts(contraption: &Contraption) -> Vec<(Coord, Direction)> {
+    let (width, height) = (contraption.width(), contraption.height());
+
+    let columns = (0..width).flat_map(|x| [((x, 0), Direction::Down), ((x, height - 1), Direction::Up)]);
+    let rows = (0..height).flat_map(|y| [((0, y), Direction::Right), ((width - 1, y), Direction::Left)]);
+
+    columns.chain(rows).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_energises_tiles() {
+        let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+        let contraption = parse_contraption(input).unwrap();
+        let energised = trace(&contraption, (0, 0), Direction::Right);
+        assert_eq!(energised.count(), 46);
+        assert_eq!(energised.to_string(), "######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..");
+        assert_eq!(trace(&contraption, (3, 0), Direction::Down).count(), 51);
+    }
+
+    #[test]
+    fn it_tracks_each_direction_crossing_a_tile() {
+        // The beam splits at the bottom, and each half loops around the mirrors in the other's path
+        let contraption = parse_contraption(r"/.\
+...
+\-/").unwrap();
+        let energised = trace(&contraption, (1, 2), Direction::Up);
+
+        assert_eq!(energised.directions((1, 2)), 3);
+        assert_eq!(energised.directions((0, 1)), 2);
+        assert!(!energised.contains((1, 1)));
+        assert_eq!(energised.count(), 8);
+    }
+
+    #[test]
+    fn it_colours_tiles_by_directions_crossed() {
+        let contraption = parse_contraption(r"/.\
+...
+\-/").unwrap();
+        let image = trace(&contraption, (1, 2), Direction::Up).to_ppm();
+        let (header, pixels) = image.split_at(11);
+
+        assert_eq!(header, b"P6\n3 3\n255\n");
+        assert_eq!(pixels.len(), 27);
+        assert_eq!(pixels[12..15], [24, 24, 36]);
+        assert_eq!(pixels[21..24], [240, 170, 50]);
+    }
+
+    #[test]
+    fn it_starts_from_every_edge_tile() {
+        let contraption = parse_contraption("...\n...").unwrap();
+        let starts = edge_starts(&contraption);
+
+        assert_eq!(starts.len(), 10);
+        assert!(starts.contains(&((2, 1), Direction::Up)));
+        assert!(starts.contains(&((2, 1), Direction::Left)));
+        assert!(starts.iter().all(|&(coord, _)| contraption.contains(coord)));
+    }
+
+    #[test]
+    fn it_traces_long_beams_without_recursing() {
+        let contraption = parse_contraption(&".".repeat(200_000)).unwrap();
+        assert_eq!(trace(&contraption, (0, 0), Direction::Right).count(), 200_000);
+    }
+}
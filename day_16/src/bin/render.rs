@@ -0,0 +1,56 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_core::Direction;
+use clap::Parser;
+use day_16::beam;
+
+// Show which tiles are energised, either as text or as an image
+#[derive(Parser)]
+#[command(about = "Render the tiles energised by the beam of light")]
+struct Cli {
+    /// Path to the puzzle input, read from AOC_INPUT or stdin if not provided
+    input: Option<PathBuf>,
+    /// Start from the edge tile which energises the most tiles, rather than the top left
+    #[arg(long)]
+    best: bool,
+    /// Write a PPM image to this path, with brighter tiles crossed in more directions
+    #[arg(long)]
+    ppm: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input = aoc_core::input::load(cli.input.as_deref()).expect("Unable to read input");
+
+    let contraption = match beam::parse_contraption(&input) {
+        Ok(contraption) => contraption,
+        Err(err) => {
+            eprintln!("{}", err.report(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let energised = match cli.best {
+        true => beam::edge_starts(&contraption)
+            .into_iter()
+            .map(|(start, direction)| beam::trace(&contraption, start, direction))
+            .max_by_key(beam::Energised::count)
+            .unwrap(),
+        false => beam::trace(&contraption, (0, 0), Direction::Right),
+    };
+
+    match cli.ppm {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, energised.to_ppm()) {
+                eprintln!("Unable to write {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+            println!("Wrote {} energised tiles to {}", energised.count(), path.display());
+        },
+        None => println!("{}", energised),
+    }
+
+    ExitCode::SUCCESS
+}
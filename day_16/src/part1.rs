@@ -0,0 +1,28 @@
+use aoc_core::{solution, Answer, Direction, ParseError};
+
+use crate::beam::{self, Contraption};
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    beam::parse_contraption(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, beam::parse_contraption, solve)
+}
+
+fn solve(contraption: Contraption) -> usize {
+    beam::trace(&contraption, (0, 0), Direction::Right).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_invalid_tiles() {
+        let input = r".|...\....
+|.-.\..#..";
+        let result = part1(input);
+        assert_eq!(result, Err(ParseError::new(1, 7, "invalid tile '#'")));
+    }
+}
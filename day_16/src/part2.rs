@@ -0,0 +1,32 @@
+use aoc_core::{solution, Answer, ParseError};
+use rayon::prelude::*;
+
+use crate::beam::{self, Contraption};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    beam::parse_contraption(input).map(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::timed(input, beam::parse_contraption, solve)
+}
+
+// Each trace is independent, so the edge tiles are shared out across threads
+fn solve(contraption: Contraption) -> usize {
+    beam::edge_starts(&contraption)
+        .into_par_iter()
+        .map(|(start, direction)| beam::trace(&contraption, start, direction).count())
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_starts_beams_along_every_edge_of_narrow_contraptions() {
+        assert_eq!(part2(".\n|\n-\n."), Ok(3));
+        assert_eq!(part2(".|-."), Ok(3));
+    }
+}
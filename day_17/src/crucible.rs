@@ -0,0 +1,101 @@
+use aoc_core::{search, Coord, Direction, Grid, ParseError};
+
+// Heat lost by moving into each block of the city
+pub type City = Grid<u32>;
+
+pub fn parse_city(input: &str) -> Result<City, ParseError> {
+    Grid::parse(input, |char| char.to_digit(10))
+}
+
+// How far a crucible must, and can, move in a straight line before turning
+#[derive(Clone, Copy, Debug)]
+pub struct Crucible {
+    pub min_run: usize,
+    pub max_run: usize,
+}
+
+impl Crucible {
+    pub const NORMAL: Crucible = Crucible { min_run: 1, max_run: 3 };
+    pub const ULTRA: Crucible = Crucible { min_run: 4, max_run: 10 };
+}
+
+// Where the crucible is, which way it's moving, and how many blocks it has moved that way
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct State {
+    coord: Coord,
+    direction: Direction,
+    run: usize,
+}
+
+impl Crucible {
+    // States reachable in a single move, with the heat lost by moving there
+    fn moves<'a>(&'a self, city: &'a City, state: State) -> impl Iterator<Item = (State, u32)> + 'a {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| direction != state.direction.opposite())
+            .filter_map(move |direction| {
+                let run = match direction == state.direction {
+                    true if state.run < self.max_run => state.run + 1,
+                    false if state.run >= self.min_run => 1,
+                    _ => return None,
+                };
+                let coord = city.step(state.coord, direction)?;
+                Some((State { coord, direction, run }, city[coord]))
+            })
+    }
+
+    // The crucible can only stop once it has moved at least min_run blocks in a straight line
+    fn can_stop(&self, state: State, factory: Coord) -> bool {
+        state.coord == factory && state.run >= self.min_run
+    }
+}
+
+// Least heat lost moving from the top left to the bottom right block
+// Returns None if the crucible can't reach the factory
+pub fn least_heat_loss(city: &City, crucible: Crucible) -> Option<u32> {
+    let factory = (city.width() - 1, city.height() - 1);
+
+    // The crucible starts at the factory, so doesn't need to move
+    if factory == (0, 0) { return Some(0); };
+
+    // The crucible can start moving either right or down
+    let starts = [Direction::Right, Direction::Down].map(|direction| State { coord: (0, 0), direction, run: 0 });
+
+    search::dijkstra(starts, |&state| crucible.moves(city, state), |&state| crucible.can_stop(state, factory)).cost()
+}
+
+// As least_heat_loss, reporting an unreachable factory against its block in the input
+pub fn heat_loss_to_factory(city: &City, crucible: Crucible) -> Result<u32, ParseError> {
+    least_heat_loss(city, crucible)
+        .ok_or(ParseError::new(city.height() - 1, city.width() - 1, "the crucible can't reach the factory"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_turns_after_the_longest_run() {
+        let city = parse_city("11111\n99999").unwrap();
+        assert_eq!(least_heat_loss(&city, Crucible::NORMAL), Some(21));
+        assert_eq!(least_heat_loss(&parse_city("11111").unwrap(), Crucible::NORMAL), None);
+    }
+
+    #[test]
+    fn it_moves_the_shortest_run_before_stopping() {
+        let city = parse_city("11111\n11111").unwrap();
+        assert_eq!(least_heat_loss(&city, Crucible::NORMAL), Some(5));
+        assert_eq!(least_heat_loss(&city, Crucible::ULTRA), None);
+        assert_eq!(
+            heat_loss_to_factory(&city, Crucible::ULTRA),
+            Err(ParseError::new(1, 4, "the crucible can't reach the factory"))
+        );
+    }
+
+    #[test]
+    fn it_starts_at_the_factory_in_a_single_block_city() {
+        let city = parse_city("7").unwrap();
+        assert_eq!(least_heat_loss(&city, Crucible::NORMAL), Some(0));
+        assert_eq!(least_heat_loss(&city, Crucible::ULTRA), Some(0));
+    }
+}
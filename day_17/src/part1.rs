@@ -0,0 +1,14 @@
+use aoc_core::{solution, Answer, ParseError};
+use crate::crucible::{self, City, Crucible};
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    crucible::parse_city(input).and_then(solve)
+}
+
+pub fn answer(input: &str) -> Answer {
+    solution::try_timed(input, crucible::parse_city, solve)
+}
+
+fn solve(city: City) -> Result<u32, ParseError> {
+    crucible::heat_loss_to_factory(&city, Crucible::NORMAL)
+}